@@ -0,0 +1,187 @@
+use std::{
+    error::Error,
+    fmt::{self, Display},
+};
+
+use crate::{GitUrl, Scheme, ValidationError};
+
+/// Builds a `GitUrl` from its parts, deriving `fullname`, `path` and `scheme_prefix` from them
+/// rather than parsing a url string
+///
+/// The scheme defaults to `https`. Setting an organization lays the repo out as an Azure DevOps
+/// repo, see [`GitUrl::with_organization`]
+#[derive(Debug, Default, Clone)]
+pub struct GitUrlBuilder {
+    host: Option<String>,
+    owner: Option<String>,
+    name: Option<String>,
+    organization: Option<String>,
+    scheme: Option<Scheme>,
+    user: Option<String>,
+    token: Option<String>,
+    port: Option<u16>,
+    git_suffix: bool,
+}
+
+impl GitUrl {
+    /// Returns a [`GitUrlBuilder`] to construct a `GitUrl` from its parts
+    pub fn builder() -> GitUrlBuilder {
+        GitUrlBuilder::new()
+    }
+}
+
+impl GitUrlBuilder {
+    /// Returns a builder with no parts set
+    pub fn new() -> GitUrlBuilder {
+        GitUrlBuilder::default()
+    }
+
+    /// Sets the host of the repo
+    pub fn host(mut self, host: impl Into<String>) -> GitUrlBuilder {
+        self.host = Some(host.into());
+        self
+    }
+
+    /// Sets the owner of the repo
+    pub fn owner(mut self, owner: impl Into<String>) -> GitUrlBuilder {
+        self.owner = Some(owner.into());
+        self
+    }
+
+    /// Sets the name of the repo
+    pub fn name(mut self, name: impl Into<String>) -> GitUrlBuilder {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Sets the Azure DevOps organization of the repo
+    pub fn organization(mut self, organization: impl Into<String>) -> GitUrlBuilder {
+        self.organization = Some(organization.into());
+        self
+    }
+
+    /// Sets the scheme, `https` when unset
+    pub fn scheme(mut self, scheme: Scheme) -> GitUrlBuilder {
+        self.scheme = Some(scheme);
+        self
+    }
+
+    /// Sets the user of the url
+    pub fn user(mut self, user: impl Into<String>) -> GitUrlBuilder {
+        self.user = Some(user.into());
+        self
+    }
+
+    /// Sets the token (or password) of the url
+    pub fn token(mut self, token: impl Into<String>) -> GitUrlBuilder {
+        self.token = Some(token.into());
+        self
+    }
+
+    /// Sets the port of the url
+    pub fn port(mut self, port: u16) -> GitUrlBuilder {
+        self.port = Some(port);
+        self
+    }
+
+    /// Sets whether the path ends in `.git`
+    pub fn git_suffix(mut self, git_suffix: bool) -> GitUrlBuilder {
+        self.git_suffix = git_suffix;
+        self
+    }
+
+    /// Returns the `GitUrl` made up of the parts set so far
+    ///
+    /// Fails when no name was set, or when the resulting `GitUrl` does not pass
+    /// [`GitUrl::validate`] (ex. a network scheme without a host)
+    pub fn build(self) -> Result<GitUrl, BuildError> {
+        let name = match self.name {
+            Some(name) if !name.is_empty() => name,
+            _ => {
+                return Err(BuildError {
+                    kind: BuildErrorKind::MissingName,
+                })
+            }
+        };
+        let scheme = self.scheme.unwrap_or(Scheme::Https);
+
+        let mut segments = self.owner.iter().map(|s| s.as_str()).collect::<Vec<_>>();
+        segments.push(&name);
+        let fullname = segments.join("/");
+
+        let suffix = match self.git_suffix {
+            true => ".git",
+            false => "",
+        };
+        let path = match scheme {
+            Scheme::Ssh => format!("{}{}", fullname, suffix),
+            _ => format!("/{}{}", fullname, suffix),
+        };
+
+        let git_url = GitUrl {
+            host: self.host,
+            name,
+            owner: self.owner,
+            fullname,
+            scheme,
+            user: self.user,
+            token: self.token,
+            port: self.port,
+            path,
+            git_suffix: self.git_suffix,
+            // scp-like ssh urls can't express a port
+            scheme_prefix: scheme.requires_prefix()
+                || (scheme == Scheme::Ssh && self.port.is_some()),
+            ..GitUrl::default()
+        };
+        let git_url = match self.organization {
+            Some(organization) => git_url.with_organization(organization),
+            None => git_url,
+        };
+
+        git_url.validate().map_err(|err| BuildError {
+            kind: BuildErrorKind::Invalid(err),
+        })?;
+
+        Ok(git_url)
+    }
+}
+
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct BuildError {
+    kind: BuildErrorKind,
+}
+
+impl BuildError {
+    /// Returns the kind of failure encountered while building the url
+    pub fn kind(&self) -> &BuildErrorKind {
+        &self.kind
+    }
+}
+
+impl Display for BuildError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            BuildErrorKind::MissingName => write!(f, "URL has no repo name"),
+            BuildErrorKind::Invalid(_) => write!(f, "built URL is invalid"),
+        }
+    }
+}
+
+impl Error for BuildError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match &self.kind {
+            BuildErrorKind::MissingName => None,
+            BuildErrorKind::Invalid(err) => Some(err),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum BuildErrorKind {
+    #[non_exhaustive]
+    MissingName,
+    #[non_exhaustive]
+    Invalid(ValidationError),
+}
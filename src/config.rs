@@ -0,0 +1,115 @@
+use std::{
+    error::Error,
+    fmt::{self, Display},
+};
+use tracing::debug;
+
+use crate::{FromStrError, GitUrl};
+
+impl GitUrl {
+    /// Finds the `url` of `[remote "<remote>"]` in a git config blob (ex. the contents of
+    /// `.git/config`) and parses it for metadata
+    pub fn parse_from_git_config(config: &str, remote: &str) -> Result<GitUrl, GitConfigError> {
+        let mut in_remote = false;
+
+        for line in config.lines().map(|line| strip_comment(line).trim()) {
+            if line.starts_with('[') {
+                in_remote = is_remote_section(line, remote);
+                continue;
+            }
+
+            if !in_remote {
+                continue;
+            }
+
+            if let Some((key, value)) = line.split_once('=') {
+                if key.trim().eq_ignore_ascii_case("url") {
+                    let url = value.trim().trim_matches('"');
+                    debug!("Found url for remote {:?}: {:?}", remote, url);
+
+                    return GitUrl::parse(url).map_err(|err| GitConfigError {
+                        kind: GitConfigErrorKind::ParseUrl(err),
+                    });
+                }
+            }
+        }
+
+        Err(GitConfigError {
+            kind: GitConfigErrorKind::MissingRemote {
+                remote: remote.to_owned(),
+            },
+        })
+    }
+}
+
+/// `strip_comment` removes a trailing `#` or `;` comment from `line`, leaving either character
+/// alone within a quoted string, ex. `[remote "a;b"]`
+fn strip_comment(line: &str) -> &str {
+    let mut quoted = false;
+    let mut escaped = false;
+
+    for (i, c) in line.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' => escaped = true,
+            '"' => quoted = !quoted,
+            '#' | ';' if !quoted => return &line[..i],
+            _ => {}
+        }
+    }
+
+    line
+}
+
+/// Section names are case-insensitive, but subsection names (the remote) are not
+fn is_remote_section(line: &str, remote: &str) -> bool {
+    let header = line.trim_start_matches('[').trim_end_matches(']');
+
+    match header.split_once(char::is_whitespace) {
+        Some((section, subsection)) => {
+            section.eq_ignore_ascii_case("remote") && subsection.trim() == format!("\"{}\"", remote)
+        }
+        None => false,
+    }
+}
+
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct GitConfigError {
+    kind: GitConfigErrorKind,
+}
+
+impl GitConfigError {
+    /// Returns the kind of failure encountered while reading the git config
+    pub fn kind(&self) -> &GitConfigErrorKind {
+        &self.kind
+    }
+}
+
+impl Display for GitConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            GitConfigErrorKind::MissingRemote { remote } => {
+                write!(f, "no url configured for remote `{}`", remote)
+            }
+            GitConfigErrorKind::ParseUrl(_) => write!(f, "unable to parse remote url"),
+        }
+    }
+}
+
+impl Error for GitConfigError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match &self.kind {
+            GitConfigErrorKind::MissingRemote { remote: _ } => None,
+            GitConfigErrorKind::ParseUrl(err) => Some(err),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum GitConfigErrorKind {
+    #[non_exhaustive]
+    MissingRemote { remote: String },
+    #[non_exhaustive]
+    ParseUrl(FromStrError),
+}
@@ -0,0 +1,360 @@
+use std::{
+    error::Error,
+    fmt::{self, Display},
+};
+
+use percent_encoding::{AsciiSet, CONTROLS};
+
+use crate::{encode_userinfo, route, trim_git_suffix, DisplayEncoded, GitUrl, Provider, Scheme};
+
+/// The URL spec's path percent-encode set. `%` is left alone so existing escapes survive
+pub(crate) const PATH: &AsciiSet = &CONTROLS
+    .add(b' ')
+    .add(b'"')
+    .add(b'#')
+    .add(b'<')
+    .add(b'>')
+    .add(b'?')
+    .add(b'`')
+    .add(b'{')
+    .add(b'}');
+
+/// The URL spec's userinfo percent-encode set, plus `%` since users and tokens are stored
+/// decoded
+pub(crate) const USERINFO: &AsciiSet = &PATH
+    .add(b'%')
+    .add(b'/')
+    .add(b':')
+    .add(b';')
+    .add(b'=')
+    .add(b'@')
+    .add(b'[')
+    .add(b'\\')
+    .add(b']')
+    .add(b'^')
+    .add(b'|');
+
+impl GitUrl {
+    /// Returns the canonical url to clone the repo from
+    ///
+    /// Ssh-family urls stay ssh, keeping the ssh user, while every other network scheme becomes
+    /// `https`. `git://` urls only do so on the public hosting services, since a self-hosted git
+    /// daemon has no https endpoint. The token and default ports are dropped, and the `.git`
+    /// suffix is always appended
+    pub fn canonical_clone_url(&self) -> Result<String, CanonicalUrlError> {
+        let host = match (&self.scheme, &self.host) {
+            (Scheme::File, _) => {
+                return Err(CanonicalUrlError {
+                    kind: CanonicalUrlErrorKind::UnsupportedScheme(self.scheme),
+                })
+            }
+            (_, Some(host)) => host,
+            (_, None) => {
+                return Err(CanonicalUrlError {
+                    kind: CanonicalUrlErrorKind::MissingHost,
+                })
+            }
+        };
+
+        let repo_path = self.repo_path();
+
+        match self.scheme {
+            Scheme::Ssh | Scheme::GitSsh => {
+                // git takes the user of scp syntax as written, so it is only encoded in the
+                // `ssh://` form
+                let user = |encode: bool| match (&self.user, encode) {
+                    (Some(user), true) => format!("{}@", encode_userinfo(user)),
+                    (Some(user), false) => format!("{}@", user),
+                    (None, _) => String::new(),
+                };
+
+                Ok(match self.non_default_port() {
+                    Some(port) => {
+                        format!("ssh://{}{}:{}/{}.git", user(true), host, port, repo_path)
+                    }
+                    None => format!("{}{}:{}.git", user(false), host, repo_path),
+                })
+            }
+            Scheme::Git if !self.host_is_known_public() => {
+                let port = match self.non_default_port() {
+                    Some(port) => format!(":{}", port),
+                    None => String::new(),
+                };
+
+                Ok(format!("git://{}{}/{}.git", host, port, repo_path))
+            }
+            _ => {
+                let port = match (&self.scheme, self.non_default_port()) {
+                    (Scheme::Https, Some(port)) => format!(":{}", port),
+                    _ => String::new(),
+                };
+
+                Ok(format!("https://{}{}/{}.git", host, port, repo_path))
+            }
+        }
+    }
+
+    /// Returns the `https://host/owner/repo` web link to the repo, without auth info or a `.git`
+    /// suffix. `None` for file urls, or when there is no host
+    ///
+    /// Repos on organization hosts link to Azure DevOps' web shape,
+    /// `https://dev.azure.com/org/project/_git/repo`, also when parsed from an ssh url
+    pub fn as_https_without_suffix(&self) -> Option<String> {
+        if self.scheme == Scheme::File {
+            return None;
+        }
+
+        let host = self.host.as_ref()?;
+        let (host, repo_path) = match (&self.organization, &self.owner, self.scheme) {
+            // Legacy visualstudio.com organizations are also served from dev.azure.com
+            (Some(organization), Some(owner), Scheme::Ssh) => (
+                match host.ends_with(".visualstudio.com") {
+                    true => "dev.azure.com",
+                    false => host.strip_prefix("ssh.").unwrap_or(host),
+                },
+                format!("{}/{}/_git/{}", organization, owner, self.name),
+            ),
+            _ => (host.as_str(), self.repo_path()),
+        };
+
+        let port = match (&self.scheme, self.non_default_port()) {
+            (Scheme::Https, Some(port)) => format!(":{}", port),
+            _ => String::new(),
+        };
+
+        Some(format!("https://{}{}/{}", host, port, repo_path))
+    }
+
+    /// Returns the url in the scp-like ssh form, `git@host:owner/repo.git`
+    ///
+    /// The user is set to `git`, and the token is cleared. The port is only kept when it
+    /// already was an ssh port, in which case the url stays in the `ssh://` form, since scp-like
+    /// urls can't express a port. Azure DevOps urls are moved to the ssh host and path shape
+    pub fn to_ssh(&self) -> GitUrl {
+        let mut ssh = self.with_scheme_form(Scheme::Ssh);
+        ssh.scheme_prefix = ssh.port.is_some();
+        ssh.user = Some("git".to_string());
+        ssh.host = match self.host.as_deref() {
+            Some("dev.azure.com") => Some("ssh.dev.azure.com".to_string()),
+            _ => ssh.host,
+        };
+        ssh
+    }
+
+    /// Returns the url in the `https://host/owner/repo.git` form, without auth info
+    ///
+    /// The port is only kept when it already was an `https` port. Azure DevOps urls are moved
+    /// to the https host and path shape
+    pub fn to_https(&self) -> GitUrl {
+        let mut https = self.with_scheme_form(Scheme::Https);
+        https.scheme_prefix = true;
+        https.user = None;
+        https.host = match self.host.as_deref() {
+            Some("ssh.dev.azure.com") => Some("dev.azure.com".to_string()),
+            _ => https.host,
+        };
+        https
+    }
+
+    /// Returns the url moved to `scheme`, with a path valid for it and without the auth info,
+    /// port, prefixes, query, fragment or web UI route of the original form
+    fn with_scheme_form(&self, scheme: Scheme) -> GitUrl {
+        let mut converted = self.clone();
+        converted.scheme = scheme;
+        converted.token = None;
+        converted.port = match self.scheme == scheme {
+            true => self.port,
+            false => None,
+        };
+        converted.git_short_form = false;
+        converted.git_plus_prefix = false;
+        converted.query = None;
+        converted.fragment = None;
+        converted.reference = None;
+
+        // Pasted web UI links are reduced to the repo they point into
+        let path = match route::web_route(self.host.as_deref(), &self.path) {
+            Some(_) => self.fullname.as_str(),
+            None => self.path.trim_start_matches('/'),
+        };
+        converted.path = match scheme {
+            Scheme::Ssh => path.to_string(),
+            _ => format!("/{}", path),
+        };
+
+        match &self.organization {
+            Some(organization) if self.scheme != scheme => {
+                converted.with_organization(organization.as_str())
+            }
+            _ => converted,
+        }
+    }
+
+    /// Returns the human-browsable `https://host/owner/repo` web url of the repo, whichever
+    /// form the url was written in. `None` for file urls, or when there is no host
+    ///
+    /// Equivalent to [`GitUrl::as_https_without_suffix`]
+    pub fn web_url(&self) -> Option<String> {
+        self.as_https_without_suffix()
+    }
+
+    /// Returns a canonical form of the url, identical for every url of the same repo whichever
+    /// way it was written: `https://host/owner/repo` with a lowercase host, without auth info,
+    /// a default port, a trailing slash or a `.git` suffix. The host is the
+    /// [`GitUrl::canonical_host`]
+    ///
+    /// File urls keep their path, as `file:///path/to/repo`, or `file:relative/repo` for a path
+    /// relative to the working directory
+    pub fn canonical(&self) -> String {
+        let mut canonical = self.clone();
+        canonical.host = self.canonical_host().map(|host| host.to_string());
+
+        match canonical.as_https_without_suffix() {
+            Some(url) => url,
+            None => {
+                let path = self.path.trim_end_matches(".git");
+                match path.starts_with('/') {
+                    true => format!("file:///{}", path.trim_start_matches('/')),
+                    false => format!("file:{}", path),
+                }
+            }
+        }
+    }
+
+    /// Returns the Bitbucket web link to the repo's source, browsing `reference` when set, ex.
+    /// `https://bitbucket.org/owner/repo/src/main/`. `None` unless the repo is hosted on
+    /// Bitbucket
+    ///
+    /// Bitbucket browses sources under `/src/` rather than GitHub's `/tree/`
+    pub fn to_bitbucket_web_url(&self) -> Option<String> {
+        if self.provider() != Provider::Bitbucket {
+            return None;
+        }
+
+        let web_url = self.web_url()?;
+        Some(match &self.reference {
+            Some(reference) => format!("{}/src/{}/", web_url, reference),
+            None => web_url,
+        })
+    }
+
+    /// Returns the anonymous `https://host/owner/repo.git` clone url of the repo, without auth
+    /// info whatever the scheme of the url. `None` for file urls, or when there is no host
+    pub fn to_anonymous_https(&self) -> Option<String> {
+        self.as_https_without_suffix()
+            .map(|url| format!("{}.git", url))
+    }
+
+    /// Returns the scp-like form (`git@host:owner/repo.git`) of the url, keeping the user of
+    /// ssh-family urls and using `git` otherwise
+    ///
+    /// scp syntax cannot express a port, so `None` is returned when a non-default port is set,
+    /// as well as for file urls and urls without a host. Azure DevOps urls take the ssh host and
+    /// path shape of [`GitUrl::to_ssh`], without a `.git` suffix
+    pub fn to_scp_like(&self) -> Option<String> {
+        if self.scheme == Scheme::File || self.non_default_port().is_some() {
+            return None;
+        }
+
+        let ssh = self.to_ssh();
+        let host = ssh.host.as_ref()?;
+        // git takes the user of scp syntax as written, without decoding it
+        let user = match (&self.scheme, &self.user) {
+            (Scheme::Ssh | Scheme::GitSsh, Some(user)) => user.as_str(),
+            _ => "git",
+        };
+
+        Some(match ssh.organization {
+            Some(_) => format!("{}@{}:{}", user, host, ssh.path),
+            None => format!("{}@{}:{}.git", user, host, self.repo_path()),
+        })
+    }
+
+    /// Returns every field of the url by name, with its value as a string, for ad-hoc
+    /// serialization or debugging dumps without the `serde` feature
+    ///
+    /// Unset options and empty `groups` are `None`, while `groups` are otherwise joined by `/`.
+    /// As with `Debug`, the token is included as is
+    pub fn to_field_map(&self) -> Vec<(&'static str, Option<String>)> {
+        let groups = match self.groups.is_empty() {
+            true => None,
+            false => Some(self.groups.join("/")),
+        };
+
+        vec![
+            ("host", self.host.clone()),
+            ("name", Some(self.name.clone())),
+            ("owner", self.owner.clone()),
+            ("organization", self.organization.clone()),
+            ("fullname", Some(self.fullname.clone())),
+            ("scheme", Some(self.scheme.to_string())),
+            ("user", self.user.clone()),
+            ("token", self.token.clone()),
+            ("port", self.port.map(|port| port.to_string())),
+            ("path", Some(self.path.clone())),
+            ("git_suffix", Some(self.git_suffix.to_string())),
+            ("scheme_prefix", Some(self.scheme_prefix.to_string())),
+            ("query", self.query.clone()),
+            ("fragment", self.fragment.clone()),
+            ("reference", self.reference.clone()),
+            ("git_short_form", Some(self.git_short_form.to_string())),
+            ("git_plus_prefix", Some(self.git_plus_prefix.to_string())),
+            ("git_prefix", Some(self.git_prefix.to_string())),
+            ("original_host", self.original_host.clone()),
+            ("groups", groups),
+            ("worktree", self.worktree.clone()),
+            ("comment", self.comment.clone()),
+        ]
+    }
+
+    /// Renders the url with the path and userinfo percent-encoded, producing a valid url even
+    /// when components hold characters such as spaces
+    pub fn to_encoded_string(&self) -> String {
+        // userinfo is always encoded by `Display`
+        DisplayEncoded(self).to_string()
+    }
+
+    /// Returns the path to the repo without a leading `/` or a `.git` suffix
+    ///
+    /// Pasted web UI links are reduced to the repo they point into
+    pub(crate) fn repo_path(&self) -> String {
+        match route::web_route(self.host.as_deref(), &self.path) {
+            Some(_) => self.fullname.clone(),
+            None => trim_git_suffix(self.path.trim_start_matches('/')).to_string(),
+        }
+    }
+}
+
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct CanonicalUrlError {
+    kind: CanonicalUrlErrorKind,
+}
+
+impl CanonicalUrlError {
+    /// Returns the kind of failure encountered while building the url
+    pub fn kind(&self) -> &CanonicalUrlErrorKind {
+        &self.kind
+    }
+}
+
+impl Display for CanonicalUrlError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            CanonicalUrlErrorKind::MissingHost => write!(f, "URL has no host"),
+            CanonicalUrlErrorKind::UnsupportedScheme(scheme) => {
+                write!(f, "no canonical clone URL for scheme `{}`", scheme)
+            }
+        }
+    }
+}
+
+impl Error for CanonicalUrlError {}
+
+#[derive(Debug)]
+pub enum CanonicalUrlErrorKind {
+    #[non_exhaustive]
+    MissingHost,
+    #[non_exhaustive]
+    UnsupportedScheme(Scheme),
+}
@@ -0,0 +1,122 @@
+use std::net::IpAddr;
+
+use percent_encoding::percent_decode_str;
+use url::Host;
+
+use crate::{GitUrl, NormalizeUrlError, NormalizeUrlErrorKind, Scheme};
+
+/// Canonical hosts of the public git hosting services
+pub(crate) const PUBLIC_HOSTS: &[&str] = &[
+    "github.com",
+    "gitlab.com",
+    "bitbucket.org",
+    "dev.azure.com",
+    "codeberg.org",
+    "git.sr.ht",
+];
+
+fn is_public_host(host: &str) -> bool {
+    let host = trim_root_dot(host);
+    PUBLIC_HOSTS.iter().any(|h| h.eq_ignore_ascii_case(host))
+}
+
+/// `trim_root_dot` removes the trailing `.` of a fully-qualified host, ex. `github.com.`, which
+/// names the same host as `github.com`
+pub(crate) fn trim_root_dot(host: &str) -> &str {
+    host.strip_suffix('.').unwrap_or(host)
+}
+
+impl GitUrl {
+    /// Returns the scheme to assume for a schemeless `owner/repo` on `host`
+    ///
+    /// Public web hosts are anonymously cloneable over `https`, while self-hosted servers are
+    /// assumed to be reached over `ssh`
+    pub fn scheme_default_for_host(host: &str) -> Scheme {
+        match is_public_host(host) {
+            true => Scheme::Https,
+            false => Scheme::Ssh,
+        }
+    }
+
+    /// Returns `true` when `host` is one of the canonical hosts of the public git hosting
+    /// services (ex. `github.com`), rather than a self-hosted server
+    pub fn host_is_known_public(&self) -> bool {
+        match &self.host {
+            Some(host) => is_public_host(host),
+            None => false,
+        }
+    }
+
+    /// Returns `true` when the host is a wildcard pattern such as `*.github.com`, as found in
+    /// config templates, rather than a literal host
+    ///
+    /// The `*` label is otherwise treated as opaque by parsing
+    pub fn is_wildcard_host(&self) -> bool {
+        match &self.host {
+            Some(host) => host.starts_with("*."),
+            None => false,
+        }
+    }
+
+    /// Returns the host with the casing of the input, ex. `GitHub.com` for
+    /// `git@GitHub.com:owner/repo.git`, while `host` is lowercased
+    pub fn host_original_case(&self) -> Option<&str> {
+        self.original_host.as_deref().or(self.host.as_deref())
+    }
+
+    /// Returns the host without the trailing `.` of a fully-qualified name, ex. `github.com`
+    /// for `https://github.com./owner/repo.git`
+    ///
+    /// `host` is kept as written, but known hosts are matched against this form
+    pub fn canonical_host(&self) -> Option<&str> {
+        self.host.as_deref().map(trim_root_dot)
+    }
+
+    /// Returns `true` when both urls point at the same host and port, ignoring case, the
+    /// trailing `.` of a fully-qualified host and an explicit default port, so `github.com` and
+    /// `github.com:443` are equal over `https`
+    pub fn host_eq(&self, other: &GitUrl) -> bool {
+        self.canonical_host_eq(other) && self.non_default_port() == other.non_default_port()
+    }
+
+    /// Returns `true` when both urls have the same [`GitUrl::canonical_host`], ignoring case
+    pub(crate) fn canonical_host_eq(&self, other: &GitUrl) -> bool {
+        match (self.canonical_host(), other.canonical_host()) {
+            (Some(host), Some(other_host)) => host.eq_ignore_ascii_case(other_host),
+            (None, None) => true,
+            _ => false,
+        }
+    }
+
+    /// Returns `true` when `host` is an IPv4 or IPv6 literal, with or without the brackets
+    /// around IPv6 addresses
+    pub fn is_ip_host(&self) -> bool {
+        match &self.host {
+            Some(host) => host
+                .trim_start_matches('[')
+                .trim_end_matches(']')
+                .parse::<IpAddr>()
+                .is_ok(),
+            None => false,
+        }
+    }
+
+    /// Returns a `GitUrl` with a Unicode `host` converted to its ASCII punycode form, ex.
+    /// `xn--bcher-kva.de` for `bücher.de`
+    ///
+    /// Hosts of special schemes (ex. `https://`) are already converted while parsing, but
+    /// other schemes (ex. `ssh://`) keep them percent-encoded. ASCII and IP hosts are unchanged
+    pub fn reencode_idna(&self) -> Result<GitUrl, NormalizeUrlError> {
+        let mut new_giturl = self.clone();
+
+        if let Some(host) = &self.host {
+            let decoded = percent_decode_str(host).decode_utf8_lossy();
+            let host = Host::parse(&decoded).map_err(|err| NormalizeUrlError {
+                kind: NormalizeUrlErrorKind::UrlParse(err),
+            })?;
+            new_giturl.host = Some(host.to_string());
+        }
+
+        Ok(new_giturl)
+    }
+}
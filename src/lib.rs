@@ -1,18 +1,79 @@
+use percent_encoding::{percent_decode_str, utf8_percent_encode};
+use std::cmp::Ordering;
 use std::fmt::Display;
+use std::hash::{Hash, Hasher};
+use std::net::IpAddr;
 use std::str::FromStr;
+use std::str::Utf8Error;
 use std::{error::Error, fmt};
 use tracing::debug;
 use url::Url;
 
+mod builder;
+mod config;
+mod convert;
+mod host;
+mod options;
+mod provider;
+mod route;
 mod scheme;
+#[cfg(feature = "serde")]
+mod serde;
+mod validate;
 
+pub use crate::builder::{BuildError, BuildErrorKind, GitUrlBuilder};
+pub use crate::config::{GitConfigError, GitConfigErrorKind};
+pub use crate::convert::{CanonicalUrlError, CanonicalUrlErrorKind};
+pub use crate::options::ParseOptions;
+pub use crate::provider::Provider;
 pub use crate::scheme::Scheme;
+pub use crate::validate::{ValidationError, ValidationErrorKind};
+
+/// Prefixes of GitHub personal access and OAuth tokens
+const GITHUB_TOKEN_PREFIXES: &[&str] = &["ghp_", "gho_", "github_pat_"];
+
+/// Options of `git clone` that take their value as the following argument, as of git 2.49
+const CLONE_OPTIONS_WITH_VALUE: &[&str] = &[
+    "-b",
+    "--branch",
+    "--bundle-uri",
+    "-c",
+    "--config",
+    "--depth",
+    "--filter",
+    "-j",
+    "--jobs",
+    "-o",
+    "--origin",
+    "--ref-format",
+    "--reference",
+    "--reference-if-able",
+    "--revision",
+    "--separate-git-dir",
+    "--server-option",
+    "--shallow-exclude",
+    "--shallow-since",
+    "--template",
+    "-u",
+    "--upload-pack",
+];
+
+/// Options of `git` itself, given before the subcommand, that take their value as the
+/// following argument, as of git 2.49
+const GIT_OPTIONS_WITH_VALUE: &[&str] = &[
+    "-C",
+    "-c",
+    "--config-env",
+    "--git-dir",
+    "--namespace",
+    "--work-tree",
+];
 
 /// GitUrl represents an input url that is a url used by git
 /// Internally during parsing the url is sanitized and uses the `url` crate to perform
 /// the majority of the parsing effort, and with some extra handling to expose
 /// metadata used my many git hosting services
-#[derive(Debug, PartialEq, Eq, Clone)]
+#[derive(Debug, Clone)]
 pub struct GitUrl {
     /// The fully qualified domain name (FQDN) or IP of the repo
     pub host: Option<String>,
@@ -38,25 +99,179 @@ pub struct GitUrl {
     pub git_suffix: bool,
     /// Indicate if url explicitly uses its scheme
     pub scheme_prefix: bool,
+    /// The query string, excluding the leading `?`
+    pub query: Option<String>,
+    /// The fragment, excluding the leading `#`
+    pub fragment: Option<String>,
+    /// The branch, tag or commit a web UI link points at (ex. `github.com/owner/repo/tree/main`)
+    pub reference: Option<String>,
+    /// Indicate if url uses the `git:host/path` short form of the git scheme
+    pub git_short_form: bool,
+    /// Indicate if url uses the `git+` compound scheme prefix, as in `git+file://`
+    pub git_plus_prefix: bool,
+    /// Indicate if url uses the `git::` forced-transport prefix of Terraform module sources,
+    /// as in `git::https://`
+    pub git_prefix: bool,
+    /// The host as written in the input, when its case differs from the lowercased `host`.
+    /// See [`GitUrl::host_original_case`]
+    ///
+    /// Only a spelling of `host`, so it is left out of comparisons and hashing
+    pub original_host: Option<String>,
+    /// Every group the repo is nested under, outermost first, on hosts with subgroups (ex.
+    /// `["group", "subgroup"]` for `gitlab.com/group/subgroup/repo`). The last is `owner`.
+    /// See [`ParseOptions::subgroup_hosts`]
+    pub groups: Vec<String>,
+    /// The worktree hint following ` -- ` in a local path. See [`ParseOptions::worktree_hints`]
+    pub worktree: Option<String>,
+    /// The trailing ` # comment` annotating the url. See [`ParseOptions::trailing_comments`]
+    pub comment: Option<String>,
 }
 
 /// Build the printable GitUrl from its components
 impl fmt::Display for GitUrl {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let scheme_prefix = match self.scheme_prefix {
-            true => format!("{}://", self.scheme),
-            false => String::new(),
+        self.fmt_with_auth(f, true, false)
+    }
+}
+
+/// Compares every field but `original_host`, so urls differing only in the case of their host
+/// are equal
+impl PartialEq for GitUrl {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for GitUrl {}
+
+/// Hashes the same fields `Eq` compares
+impl Hash for GitUrl {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.host.hash(state);
+        self.name.hash(state);
+        self.owner.hash(state);
+        self.organization.hash(state);
+        self.fullname.hash(state);
+        self.scheme.hash(state);
+        self.user.hash(state);
+        self.token.hash(state);
+        self.port.hash(state);
+        self.path.hash(state);
+        self.git_suffix.hash(state);
+        self.scheme_prefix.hash(state);
+        self.query.hash(state);
+        self.fragment.hash(state);
+        self.reference.hash(state);
+        self.git_short_form.hash(state);
+        self.git_plus_prefix.hash(state);
+        self.git_prefix.hash(state);
+        self.groups.hash(state);
+        self.worktree.hash(state);
+        self.comment.hash(state);
+    }
+}
+
+/// Orders urls by `host`, then `owner`, then `name`. The remaining fields only break ties, to
+/// stay consistent with `Eq`
+impl Ord for GitUrl {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.host
+            .cmp(&other.host)
+            .then_with(|| self.owner.cmp(&other.owner))
+            .then_with(|| self.name.cmp(&other.name))
+            .then_with(|| self.organization.cmp(&other.organization))
+            .then_with(|| self.fullname.cmp(&other.fullname))
+            .then_with(|| self.scheme.cmp(&other.scheme))
+            .then_with(|| self.user.cmp(&other.user))
+            .then_with(|| self.token.cmp(&other.token))
+            .then_with(|| self.port.cmp(&other.port))
+            .then_with(|| self.path.cmp(&other.path))
+            .then_with(|| self.git_suffix.cmp(&other.git_suffix))
+            .then_with(|| self.scheme_prefix.cmp(&other.scheme_prefix))
+            .then_with(|| self.query.cmp(&other.query))
+            .then_with(|| self.fragment.cmp(&other.fragment))
+            .then_with(|| self.reference.cmp(&other.reference))
+            .then_with(|| self.git_short_form.cmp(&other.git_short_form))
+            .then_with(|| self.git_plus_prefix.cmp(&other.git_plus_prefix))
+            .then_with(|| self.git_prefix.cmp(&other.git_prefix))
+            .then_with(|| self.groups.cmp(&other.groups))
+            .then_with(|| self.worktree.cmp(&other.worktree))
+            .then_with(|| self.comment.cmp(&other.comment))
+    }
+}
+
+impl PartialOrd for GitUrl {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Prints a `GitUrl` without its auth info. See [`GitUrl::display_safe`]
+struct DisplaySafe<'a>(&'a GitUrl);
+
+impl fmt::Display for DisplaySafe<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt_with_auth(f, false, false)
+    }
+}
+
+/// Prints a `GitUrl` with its path percent-encoded. See [`GitUrl::to_encoded_string`]
+pub(crate) struct DisplayEncoded<'a>(pub(crate) &'a GitUrl);
+
+impl fmt::Display for DisplayEncoded<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt_with_auth(f, true, true)
+    }
+}
+
+impl GitUrl {
+    fn fmt_with_auth(
+        &self,
+        f: &mut fmt::Formatter,
+        include_auth: bool,
+        encode_path: bool,
+    ) -> fmt::Result {
+        // Schemes that can't be spelled without their prefix get one whatever `scheme_prefix` says,
+        // as do ssh urls with a port, since the scp-like form has no room for one
+        let has_scheme_prefix = self.scheme_prefix
+            || self.scheme.requires_prefix()
+            || (self.scheme == Scheme::Ssh && self.port.is_some());
+        let scheme_prefix = match (has_scheme_prefix, self.git_short_form) {
+            (true, true) => format!("{}:", self.scheme),
+            (true, false) => format!("{}://", self.scheme),
+            (false, _) => String::new(),
+        };
+
+        // `git+` has to be followed by a scheme, so urls written as `git+file:path` keep their
+        // `file:`
+        let scheme_prefix = match (self.git_plus_prefix, has_scheme_prefix) {
+            (true, true) => format!("git+{}", scheme_prefix),
+            (true, false) => format!("git+{}:", self.scheme),
+            (false, _) => scheme_prefix,
         };
 
+        let scheme_prefix = match self.git_prefix {
+            true => format!("git::{}", scheme_prefix),
+            false => scheme_prefix,
+        };
+
+        // git takes the user of scp syntax as written, so it is only encoded in `scheme://` urls
+        let user = match has_scheme_prefix {
+            true => self.user.as_deref().map(encode_userinfo),
+            false => self.user.clone(),
+        };
+        let token = self.token.as_deref().map(encode_userinfo);
+
         let auth_info = match self.scheme {
-            Scheme::Ssh | Scheme::Git | Scheme::GitSsh => {
-                if let Some(user) = &self.user {
+            _ if !include_auth => String::new(),
+            Scheme::Ssh | Scheme::Git | Scheme::GitSsh | Scheme::Rsync => {
+                if let Some(user) = &user {
                     format!("{}@", user)
                 } else {
                     String::new()
                 }
             }
-            Scheme::Http | Scheme::Https => match (&self.user, &self.token) {
+            Scheme::Http | Scheme::Https | Scheme::Ftp | Scheme::Ftps => match (&user, &token) {
                 (Some(user), Some(token)) => format!("{}:{}@", user, token),
                 (Some(user), None) => format!("{}@", user),
                 (None, Some(token)) => format!("{}@", token),
@@ -75,18 +290,42 @@ impl fmt::Display for GitUrl {
             None => String::new(),
         };
 
+        let path = match (&self.scheme, encode_path) {
+            (_, true) => utf8_percent_encode(&self.path, convert::PATH).to_string(),
+            // Local paths are parsed as written, so they are printed without url escapes.
+            // Relative paths are stored as written already
+            (Scheme::File, false) if !has_scheme_prefix && self.path.starts_with('/') => {
+                decode_component(&self.path)
+            }
+            (_, false) => self.path.to_string(),
+        };
+
         let path = match &self.scheme {
+            // `ssh://host:path` does not parse, the `:` only separates the path in scp-like urls
             Scheme::Ssh => {
-                if self.port.is_some() {
-                    format!("/{}", &self.path)
+                if has_scheme_prefix {
+                    format!("/{}", path)
                 } else {
-                    format!(":{}", &self.path)
+                    format!(":{}", path)
                 }
             }
-            _ => (&self.path).to_string(),
+            _ => path,
+        };
+
+        let query = match &self.query {
+            Some(q) => format!("?{}", q),
+            None => String::new(),
+        };
+
+        let fragment = match &self.fragment {
+            Some(fragment) => format!("#{}", fragment),
+            None => String::new(),
         };
 
-        let git_url_str = format!("{}{}{}{}{}", scheme_prefix, auth_info, host, port, path);
+        let git_url_str = format!(
+            "{}{}{}{}{}{}{}",
+            scheme_prefix, auth_info, host, port, path, query, fragment
+        );
 
         write!(f, "{}", git_url_str)
     }
@@ -107,6 +346,16 @@ impl Default for GitUrl {
             path: "".to_string(),
             git_suffix: false,
             scheme_prefix: false,
+            query: None,
+            fragment: None,
+            reference: None,
+            git_short_form: false,
+            git_plus_prefix: false,
+            git_prefix: false,
+            original_host: None,
+            groups: Vec::new(),
+            worktree: None,
+            comment: None,
         }
     }
 }
@@ -133,10 +382,20 @@ impl Display for FromStrError {
             FromStrErrorKind::MalformedGitUrl => {
                 write!(f, "unknown format of git URL `{}`", self.url)
             }
+            FromStrErrorKind::InvalidUtf8(_) => {
+                write!(f, "URL `{}` is not valid UTF-8", self.url)
+            }
         }
     }
 }
 
+impl FromStrError {
+    /// Returns the kind of failure encountered while parsing
+    pub fn kind(&self) -> &FromStrErrorKind {
+        &self.kind
+    }
+}
+
 impl Error for FromStrError {
     fn source(&self) -> Option<&(dyn Error + 'static)> {
         match &self.kind {
@@ -144,11 +403,13 @@ impl Error for FromStrError {
             FromStrErrorKind::UrlHost => None,
             FromStrErrorKind::UnsupportedScheme => None,
             FromStrErrorKind::MalformedGitUrl => None,
+            FromStrErrorKind::InvalidUtf8(err) => Some(err),
         }
     }
 }
 
 #[derive(Debug)]
+#[non_exhaustive]
 pub enum FromStrErrorKind {
     #[non_exhaustive]
     NormalizeUrl(NormalizeUrlError),
@@ -158,6 +419,8 @@ pub enum FromStrErrorKind {
     UnsupportedScheme,
     #[non_exhaustive]
     MalformedGitUrl,
+    #[non_exhaustive]
+    InvalidUtf8(Utf8Error),
 }
 
 impl FromStr for GitUrl {
@@ -169,23 +432,687 @@ impl FromStr for GitUrl {
 }
 
 impl GitUrl {
+    /// Returns a `GitUrl` that renders with (or without) the `git::` prefix, as used for
+    /// Terraform module sources
+    pub fn with_git_prefix(&self, git_prefix: bool) -> GitUrl {
+        let mut new_giturl = self.clone();
+        new_giturl.git_prefix = git_prefix;
+        new_giturl
+    }
+
+    /// Returns a `GitUrl` with `path` replaced, and the metadata parsed from it (`owner`,
+    /// `name`, `fullname`...) extracted again by the default [`GitUrl::parse`] rules
+    pub fn with_path(&self, path: impl Into<String>) -> Result<GitUrl, FromStrError> {
+        let path = path.into();
+        let path = path.trim_start_matches('/');
+
+        let mut rebuilt = self.clone();
+        rebuilt.path = match self.scheme {
+            Scheme::Ssh => path.to_string(),
+            _ => format!("/{}", path),
+        };
+
+        let mut git_url = GitUrl::parse(&rebuilt.to_string())?;
+        git_url.scheme_prefix = self.scheme_prefix;
+        Ok(git_url)
+    }
+
+    /// Returns `GitUrl` after removing `query` and `fragment` values, leaving the bare clone url
+    pub fn strip_query_and_fragment(&self) -> GitUrl {
+        let mut new_giturl = self.clone();
+        new_giturl.query = None;
+        new_giturl.fragment = None;
+        new_giturl
+    }
+
     /// Returns `GitUrl` after removing `user` and `token` values
     /// Intended use-case is for non-destructive printing GitUrl excluding any embedded auth info
     pub fn trim_auth(&self) -> GitUrl {
         let mut new_giturl = self.clone();
-        new_giturl.user = None;
-        new_giturl.token = None;
+        new_giturl.trim_auth_mut();
+        new_giturl
+    }
+
+    /// Removes `user` and `token` values in place, without cloning the rest of the `GitUrl`
+    pub fn trim_auth_mut(&mut self) {
+        self.user = None;
+        self.token = None;
+    }
+
+    /// Returns `GitUrl` with the conventional `git` user when it is an ssh-family url without a
+    /// user. Other urls are returned unchanged
+    pub fn with_default_user_if_ssh(&self) -> GitUrl {
+        let mut new_giturl = self.clone();
+        if matches!(self.scheme, Scheme::Ssh | Scheme::GitSsh) && self.user.is_none() {
+            new_giturl.user = Some("git".to_string());
+        }
         new_giturl
     }
 
+    /// Returns `GitUrl` moved under `organization`, laid out as an Azure DevOps repo
+    ///
+    /// `fullname` is recomputed in Azure's `organization/owner/name` order, and `path` is rebuilt
+    /// as `organization/owner/_git/name` (or `v3/organization/owner/name` for ssh)
+    pub fn with_organization(&self, organization: impl Into<String>) -> GitUrl {
+        let mut new_giturl = self.clone();
+        let organization = organization.into();
+
+        let mut segments = vec![organization.as_str()];
+        segments.extend(self.owner.as_deref());
+
+        let suffix = match self.git_suffix {
+            true => ".git",
+            false => "",
+        };
+
+        new_giturl.path = match self.scheme {
+            Scheme::Ssh => format!("v3/{}/{}{}", segments.join("/"), self.name, suffix),
+            _ => format!("/{}/_git/{}{}", segments.join("/"), self.name, suffix),
+        };
+
+        segments.push(&self.name);
+        new_giturl.fullname = segments.join("/");
+        new_giturl.organization = Some(organization);
+        new_giturl
+    }
+
+    /// Returns the `GitUrl` with `owner` transformed by `f`, if there is one, and `fullname`
+    /// recomputed. `path` is left as is
+    pub fn map_owner(mut self, f: impl FnOnce(String) -> String) -> GitUrl {
+        self.owner = self.owner.map(f);
+        if let (Some(owner), Some(group)) = (&self.owner, self.groups.last_mut()) {
+            *group = owner.clone();
+        }
+        self.fullname = self.joined_fullname();
+        self
+    }
+
+    /// Returns the `GitUrl` with `name` transformed by `f` and `fullname` recomputed. `path` is
+    /// left as is
+    pub fn map_name(mut self, f: impl FnOnce(String) -> String) -> GitUrl {
+        self.name = f(self.name);
+        self.fullname = self.joined_fullname();
+        self
+    }
+
+    /// Returns the `GitUrl` with `path` transformed by `f`. The metadata parsed from the path
+    /// is left as is
+    pub fn map_path(mut self, f: impl FnOnce(String) -> String) -> GitUrl {
+        self.path = f(self.path);
+        self
+    }
+
+    /// Joins `organization`, `groups`, `owner` and `name` into the `fullname` they make up
+    fn joined_fullname(&self) -> String {
+        // The innermost group is the owner
+        let parents = match self.groups.split_last() {
+            Some((_, parents)) => parents,
+            None => &[],
+        };
+
+        self.organization
+            .iter()
+            .chain(parents)
+            .chain(self.owner.iter())
+            .chain(std::iter::once(&self.name))
+            .map(|s| s.as_str())
+            .filter(|s| !s.is_empty())
+            .collect::<Vec<_>>()
+            .join("/")
+    }
+
+    /// Returns a printable form of the url that never includes the user or token, unlike
+    /// `Display` which prints any embedded auth info
+    pub fn display_safe(&self) -> impl Display + '_ {
+        DisplaySafe(self)
+    }
+
+    /// Renders the url with any token replaced by `***`, keeping the user visible
+    pub fn redact(&self) -> String {
+        let mut redacted = self.clone();
+        if redacted.token.is_some() {
+            redacted.token = Some("***".to_string());
+        }
+        redacted.to_string()
+    }
+
+    /// Renders the url with the user visible but the token omitted entirely, so not even the
+    /// presence of a token is revealed
+    pub fn display_auth_masked(&self) -> String {
+        let mut masked = self.clone();
+        masked.token = None;
+        masked.to_string()
+    }
+
+    /// Indicate if `candidate` parses to the same remote repository, ignoring scheme, auth,
+    /// the `.git` suffix, default ports and the spelling of the host, as in
+    /// [`GitUrl::host_eq`]
+    ///
+    /// Ports are only compared between urls of the same scheme, since a port is specific to
+    /// its transport
+    pub fn equivalent_clone_url(&self, candidate: &str) -> bool {
+        let candidate = match GitUrl::parse(candidate) {
+            Ok(candidate) => candidate,
+            Err(_) => return false,
+        };
+
+        let same_port = self.scheme != candidate.scheme
+            || self.non_default_port() == candidate.non_default_port();
+
+        self.canonical_host_eq(&candidate) && self.fullname == candidate.fullname && same_port
+    }
+
+    /// Indicate if `host/fullname` matches `pattern`, where a `*` segment matches exactly one
+    /// path segment (ex. `github.com/myorg/*` or `*/*/repo`)
+    pub fn matches_glob(&self, pattern: &str) -> bool {
+        let subject = match &self.host {
+            Some(host) => format!("{}/{}", host, self.fullname),
+            None => self.fullname.clone(),
+        };
+
+        let subject_segments = subject.split('/').collect::<Vec<&str>>();
+        let pattern_segments = pattern.split('/').collect::<Vec<&str>>();
+
+        subject_segments.len() == pattern_segments.len()
+            && subject_segments
+                .iter()
+                .zip(pattern_segments)
+                .all(|(segment, pattern)| pattern == "*" || pattern == *segment)
+    }
+
+    /// Indicate if the url matches `template`, a partially filled `GitUrl` whose unset fields
+    /// act as wildcards: a `Scheme::Unspecified` scheme, `None` options, and empty strings or
+    /// groups. Every set field must be equal
+    ///
+    /// Unlike `==`, the flags recording how the url was spelled (ex. `scheme_prefix`) are not
+    /// compared
+    pub fn matches_template(&self, template: &GitUrl) -> bool {
+        fn option_matches<T: PartialEq>(value: &Option<T>, template: &Option<T>) -> bool {
+            template.is_none() || value == template
+        }
+
+        fn str_matches(value: &str, template: &str) -> bool {
+            template.is_empty() || value == template
+        }
+
+        (template.scheme == Scheme::Unspecified || self.scheme == template.scheme)
+            && option_matches(&self.host, &template.host)
+            && str_matches(&self.name, &template.name)
+            && option_matches(&self.owner, &template.owner)
+            && option_matches(&self.organization, &template.organization)
+            && str_matches(&self.fullname, &template.fullname)
+            && option_matches(&self.user, &template.user)
+            && option_matches(&self.token, &template.token)
+            && option_matches(&self.port, &template.port)
+            && str_matches(&self.path, &template.path)
+            && option_matches(&self.query, &template.query)
+            && option_matches(&self.fragment, &template.fragment)
+            && option_matches(&self.reference, &template.reference)
+            && (template.groups.is_empty() || self.groups == template.groups)
+            && option_matches(&self.worktree, &template.worktree)
+            && option_matches(&self.comment, &template.comment)
+    }
+
+    /// Returns `port` unless it is the default port of `scheme`
+    pub(crate) fn non_default_port(&self) -> Option<u16> {
+        self.port
+            .filter(|port| self.scheme.default_port() != Some(*port))
+    }
+
+    /// Returns the Go module import path (`host/owner/name`), as expected by `go get`
+    ///
+    /// Azure DevOps repos are imported by their web path, ex.
+    /// `dev.azure.com/org/project/_git/repo`, also when parsed from an ssh url. `None` for file
+    /// urls, or when there is no host
+    pub fn to_go_import_path(&self) -> Option<String> {
+        match (self.scheme, &self.organization) {
+            (Scheme::File, _) => None,
+            (_, Some(_)) => self
+                .as_https_without_suffix()
+                .map(|url| url.trim_start_matches("https://").to_string()),
+            _ => Some(format!("{}/{}", self.host.as_ref()?, self.fullname)),
+        }
+    }
+
+    /// Returns the `ssh://host:port/path` form of an ssh-family url without any `user@`, or
+    /// `None` for other schemes
+    pub fn ssh_url_without_user(&self) -> Option<String> {
+        match self.scheme {
+            Scheme::Ssh | Scheme::GitSsh => {}
+            _ => return None,
+        }
+
+        let host = self.host.as_ref()?;
+        let port = match self.port {
+            Some(port) => format!(":{}", port),
+            None => String::new(),
+        };
+
+        Some(format!(
+            "{}://{}{}/{}",
+            self.scheme,
+            host,
+            port,
+            self.path.trim_start_matches('/')
+        ))
+    }
+
+    /// Returns the scp-like form (`user@host:path`) of an ssh-family url, or `None` for
+    /// other schemes
+    ///
+    /// scp syntax cannot express a port, so any port is omitted
+    pub fn display_ssh_scp(&self) -> Option<String> {
+        match self.scheme {
+            Scheme::Ssh | Scheme::GitSsh => {}
+            _ => return None,
+        }
+
+        let host = self.host.as_ref()?;
+        // git takes the user of scp syntax as written, without decoding it
+        let user = match &self.user {
+            Some(user) => format!("{}@", user),
+            None => String::new(),
+        };
+
+        Some(format!(
+            "{}{}:{}",
+            user,
+            host,
+            self.path.trim_start_matches('/')
+        ))
+    }
+
+    /// Returns `path` with exactly one leading `/` removed, if present
+    pub fn path_without_leading_slash(&self) -> &str {
+        self.path.strip_prefix('/').unwrap_or(&self.path)
+    }
+
+    /// Returns the dot-separated DNS labels of `host`
+    ///
+    /// Empty when there is no host or the host is an IP literal
+    pub fn host_labels(&self) -> Vec<&str> {
+        match &self.host {
+            Some(host) if !host.starts_with('[') && host.parse::<IpAddr>().is_err() => {
+                host.split('.').collect()
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    /// Returns the `/`-separated group segments of `owner`, ex. `["group", "subgroup"]` for a
+    /// GitLab subgroup. Parsed `groups` take precedence over `owner`
+    ///
+    /// Empty when there is no owner
+    pub fn owner_segments(&self) -> Vec<&str> {
+        if !self.groups.is_empty() {
+            return self.groups.iter().map(|s| s.as_str()).collect();
+        }
+
+        match &self.owner {
+            Some(owner) => owner.split('/').filter(|s| !s.is_empty()).collect(),
+            None => Vec::new(),
+        }
+    }
+
+    /// Returns the owner segments and name joined by `separator`, ex. `group__subgroup__repo`,
+    /// for use as a directory name. The organization, when set, comes first
+    ///
+    /// Any `/` or `\` left within a segment is replaced by `separator` as well
+    pub fn owner_name_slug(&self, separator: &str) -> String {
+        self.organization
+            .as_deref()
+            .into_iter()
+            .chain(self.owner_segments())
+            .chain(std::iter::once(self.name.as_str()))
+            .map(|segment| segment.replace(['/', '\\'], separator))
+            .collect::<Vec<_>>()
+            .join(separator)
+    }
+
+    /// Returns the user of a git daemon home-relative path (`git://host/~user/repo.git`),
+    /// without the leading `~`
+    pub fn daemon_home_user(&self) -> Option<&str> {
+        match self.scheme {
+            Scheme::Git => self
+                .path
+                .strip_prefix("/~")
+                .and_then(|path| path.split('/').next()),
+            _ => None,
+        }
+    }
+
+    /// Indicate if url is a release archive download link (ex. `github.com/owner/repo/archive/v1.0.tar.gz`)
+    /// rather than a clone url
+    pub fn is_archive(&self) -> bool {
+        route::web_route(self.host.as_deref(), &self.path) == Some("archive")
+    }
+
+    /// Returns the repo page tab a pasted web UI link points at, ex. `issues` for
+    /// `https://github.com/owner/repo/issues`
+    pub fn web_tab(&self) -> Option<&str> {
+        route::web_route(self.host.as_deref(), &self.path)
+            .filter(|route| route::GITHUB_TABS.contains(route))
+    }
+
+    /// Returns the `depth` query parameter as a shallow clone hint
+    ///
+    /// `None` when the parameter is absent or not a valid number
+    pub fn clone_depth_hint(&self) -> Option<u32> {
+        let query = self.query.as_ref()?;
+        url::form_urlencoded::parse(query.as_bytes())
+            .find(|(key, _)| key == "depth")
+            .and_then(|(_, value)| value.parse().ok())
+    }
+
     /// Normalizes and parses `url` for metadata
     pub fn parse(url: &str) -> Result<GitUrl, FromStrError> {
+        GitUrl::parse_with_options(url, &ParseOptions::default())
+    }
+
+    /// Lazily parses each url yielded by `urls`
+    pub fn parse_iter<'a>(
+        urls: impl Iterator<Item = &'a str> + 'a,
+    ) -> impl Iterator<Item = Result<GitUrl, FromStrError>> + 'a {
+        urls.map(GitUrl::parse)
+    }
+
+    /// Validates `input` as UTF-8 before normalizing and parsing it for metadata
+    pub fn parse_bytes(input: &[u8]) -> Result<GitUrl, FromStrError> {
+        let url = std::str::from_utf8(input).map_err(|err| FromStrError {
+            url: String::from_utf8_lossy(input).into_owned(),
+            kind: FromStrErrorKind::InvalidUtf8(err),
+        })?;
+
+        GitUrl::parse(url)
+    }
+
+    /// Parses the repository url of a `git clone` command pasted from docs, ex.
+    /// `git clone --depth 1 https://github.com/owner/repo.git`
+    ///
+    /// The leading `git clone` is optional, and its options (with their values) are skipped,
+    /// as is the target directory following the url. Options given to `git` before `clone`,
+    /// ex. `git -C /tmp clone <url>`, are skipped too, while any other subcommand is an error
+    pub fn parse_clone_command(input: &str) -> Result<GitUrl, FromStrError> {
+        let mut args = input.split_whitespace().peekable();
+        if args.peek() == Some(&"git") {
+            args.next();
+
+            while let Some(arg) = args.next_if(|arg| arg.starts_with('-')) {
+                if GIT_OPTIONS_WITH_VALUE.contains(&arg) {
+                    args.next();
+                }
+            }
+
+            if args.next() != Some("clone") {
+                return Err(FromStrError {
+                    url: input.to_owned(),
+                    kind: FromStrErrorKind::MalformedGitUrl,
+                });
+            }
+        } else if args.peek() == Some(&"clone") {
+            args.next();
+        }
+
+        while let Some(arg) = args.next() {
+            match arg {
+                "--" => break,
+                _ if CLONE_OPTIONS_WITH_VALUE.contains(&arg) => {
+                    args.next();
+                }
+                _ if arg.starts_with('-') => {}
+                url => return GitUrl::parse(url),
+            }
+        }
+
+        match args.next() {
+            Some(url) => GitUrl::parse(url),
+            None => Err(FromStrError {
+                url: input.to_owned(),
+                kind: FromStrErrorKind::MalformedGitUrl,
+            }),
+        }
+    }
+
+    /// Resolves `relative` (ex. `../sibling.git`) against the repo url `base`, keeping its
+    /// scheme, user, host and port
+    ///
+    /// As with git submodule urls, `base` is treated as a directory, so `../sibling.git`
+    /// names a sibling of the `base` repo rather than of its parent
+    pub fn parse_relative_to(base: &GitUrl, relative: &str) -> Result<GitUrl, FromStrError> {
+        let mut segments = base
+            .path
+            .split('/')
+            .filter(|s| !s.is_empty())
+            .collect::<Vec<_>>();
+
+        for segment in relative.split('/') {
+            match segment {
+                "" | "." => {}
+                ".." => {
+                    if segments.pop().is_none() {
+                        return Err(FromStrError {
+                            url: relative.to_owned(),
+                            kind: FromStrErrorKind::MalformedGitUrl,
+                        });
+                    }
+                }
+                _ => segments.push(segment),
+            }
+        }
+
+        base.strip_query_and_fragment()
+            .with_path(segments.join("/"))
+    }
+
+    /// Indicate if `url` is a relative submodule url (ex. `../other-repo.git` in
+    /// `.gitmodules`), to be resolved against the superproject's remote before parsing
+    pub fn is_submodule_relative(url: &str) -> bool {
+        url.starts_with("./") || url.starts_with("../")
+    }
+
+    /// Resolves a `.gitmodules` submodule url against the `parent` superproject's remote, as
+    /// git does
+    ///
+    /// Relative urls (see [`GitUrl::is_submodule_relative`]) are resolved with
+    /// [`GitUrl::parse_relative_to`], so each `../` climbs one segment of the parent's path.
+    /// Other urls are parsed on their own
+    pub fn resolve_submodule(parent: &GitUrl, url: &str) -> Result<GitUrl, FromStrError> {
+        match GitUrl::is_submodule_relative(url) {
+            true => GitUrl::parse_relative_to(parent, url),
+            false => GitUrl::parse(url),
+        }
+    }
+
+    /// Parses `path` (ex. `/owner/repo.git`) as a repo on the server at `host`, reached over
+    /// `scheme`
+    ///
+    /// Unlike [`GitUrl::parse`], an absolute `path` is never taken to be a local file
+    pub fn parse_with_base_host(
+        path: &str,
+        host: &str,
+        scheme: Scheme,
+    ) -> Result<GitUrl, FromStrError> {
+        GitUrl::parse(&format!(
+            "{}://{}/{}",
+            scheme,
+            host,
+            path.trim_start_matches('/')
+        ))
+    }
+
+    /// Parses `url` with a ref spelled as a trailing `@ref` (Go, pip) or as a `#ref` fragment
+    /// (npm), ex. `https://host/owner/repo.git@v1`, storing it in `reference`
+    ///
+    /// pip's `git+` prefix, as in `git+https://host/owner/repo.git@v1#egg=name`, is dropped.
+    /// When both appear, the `@ref` is the reference and the fragment is kept. Fragments that
+    /// are not refs, such as pip's `egg=name` or a `L5` line anchor, are kept as well. A ref
+    /// takes precedence over one read from a web route's path. The ref is not part of the url
+    /// rendered by `Display`
+    pub fn parse_allowing_fragment_refs(url: &str) -> Result<GitUrl, FromStrError> {
+        // `git+ssh://` is a scheme of its own, and `git+file:` is parsed as cargo spells it
+        let url = url
+            .strip_prefix("git+")
+            .filter(|rest| match rest.split_once("://") {
+                Some((scheme, _)) => !["ssh", "file"]
+                    .iter()
+                    .any(|native| scheme.eq_ignore_ascii_case(native)),
+                None => false,
+            })
+            .unwrap_or(url);
+
+        let (url, at_ref) = split_at_ref(url);
+        let mut git_url = GitUrl::parse(&url)?;
+
+        match at_ref {
+            Some(reference) => git_url.reference = Some(reference),
+            None => {
+                if let Some(fragment) = git_url.fragment.take() {
+                    match is_fragment_ref(&fragment) {
+                        true => git_url.reference = Some(fragment),
+                        false => git_url.fragment = Some(fragment),
+                    }
+                }
+            }
+        }
+
+        Ok(git_url)
+    }
+
+    /// Extracts the metadata of a `url::Url` already in hand, without rendering and normalizing
+    /// it again
+    ///
+    /// scp-like ssh urls (`git@host:owner/repo.git`) are not valid `Url`s, and have to be parsed
+    /// with [`GitUrl::parse`]. Trailing slashes are trimmed from the path, as when parsing
+    pub fn parse_url(mut u: Url) -> Result<GitUrl, FromStrError> {
+        let path = u.path().trim_end_matches('/').to_string();
+        u.set_path(&path);
+
+        let url = u.to_string();
+        GitUrl::metadata_from_url(&url, u, &ParseOptions::default())
+    }
+
+    /// Normalizes and parses `url` for metadata, enabling the opt-in behaviors in `options`
+    pub fn parse_with_options(url: &str, options: &ParseOptions) -> Result<GitUrl, FromStrError> {
+        let url = trim_matching_quotes(url);
+
+        // Terraform forces the git transport for module sources with `git::`
+        let (url, git_prefix) = match url.strip_prefix("git::") {
+            Some(url) => (url, true),
+            None => (url, false),
+        };
+
+        // Cargo spells local git dependencies with the compound `git+file://` scheme
+        let (url, git_plus_prefix) = match url.strip_prefix("git+") {
+            Some(file_url) if file_url.starts_with("file:") => (file_url, true),
+            _ => (url, false),
+        };
+
+        let (url, comment) = match options.trailing_comments {
+            true => split_trailing_comment(url),
+            false => (url, None),
+        };
+
+        let worktree_hint = match options.worktree_hints {
+            true => url.split_once(" -- "),
+            false => None,
+        };
+
+        let mut git_url = match worktree_hint {
+            Some((repo, worktree)) => {
+                let mut git_url = GitUrl::parse_metadata(repo.trim_end(), options)?;
+                // Worktree hints only apply to local paths
+                match git_url.scheme {
+                    Scheme::File => {
+                        git_url.worktree = Some(worktree.trim().to_string());
+                        git_url
+                    }
+                    _ => GitUrl::parse_metadata(url, options)?,
+                }
+            }
+            None => GitUrl::parse_metadata(url, options)?,
+        };
+        git_url.git_plus_prefix = git_plus_prefix;
+        git_url.git_prefix = git_prefix;
+        git_url.comment = comment.map(|c| c.to_string());
+
+        if options.detect_github_tokens && git_url.token.is_none() {
+            if let Some(user) = &git_url.user {
+                if GITHUB_TOKEN_PREFIXES.iter().any(|p| user.starts_with(p)) {
+                    git_url.token = git_url.user.take();
+                }
+            }
+        }
+
+        Ok(git_url)
+    }
+
+    fn parse_metadata(url: &str, options: &ParseOptions) -> Result<GitUrl, FromStrError> {
+        // Relative paths have no url form to normalize into, so they are kept as written
+        if let Some(path) = relative_file_path(url) {
+            return GitUrl::relative_file_metadata(url, path, options);
+        }
+
         // Normalize the url so we can use Url crate to process ssh urls
-        let normalized = normalize_url(url).map_err(|err| FromStrError {
+        let normalized = normalize_unquoted_url(url).map_err(|err| FromStrError {
             url: url.to_owned(),
             kind: FromStrErrorKind::NormalizeUrl(err),
         })?;
 
+        GitUrl::metadata_from_url(url, normalized, options)
+    }
+
+    /// Extracts the metadata of the local `path`, relative to the working directory, as written
+    /// in `url`
+    fn relative_file_metadata(
+        url: &str,
+        path: &str,
+        options: &ParseOptions,
+    ) -> Result<GitUrl, FromStrError> {
+        let path = path.replace('\\', "/");
+        let path = path.trim_end_matches('/');
+
+        let splitpath = trim_git_suffix(path)
+            .rsplit_terminator('/')
+            .filter(|s| !s.is_empty())
+            .collect::<Vec<&str>>();
+
+        let name = match splitpath.first() {
+            Some(name) if !matches!(*name, "." | "..") => name.to_string(),
+            _ => {
+                return Err(FromStrError {
+                    url: url.to_owned(),
+                    kind: FromStrErrorKind::MalformedGitUrl,
+                })
+            }
+        };
+
+        let owner = match (options.file_owners, splitpath.get(1)) {
+            (true, Some(owner)) if !matches!(*owner, "." | "..") => Some(owner.to_string()),
+            _ => None,
+        };
+
+        let fullname = match &owner {
+            Some(owner) => format!("{}/{}", owner, name),
+            None => name.clone(),
+        };
+
+        Ok(GitUrl {
+            name,
+            owner,
+            fullname,
+            scheme: Scheme::File,
+            path: path.to_string(),
+            git_suffix: path.ends_with(".git"),
+            ..GitUrl::default()
+        })
+    }
+
+    /// Extracts the metadata of the already normalized `normalized`, as written in `url`
+    fn metadata_from_url(
+        url: &str,
+        normalized: Url,
+        options: &ParseOptions,
+    ) -> Result<GitUrl, FromStrError> {
         // Some pre-processing for paths
         // REFACTOR: write Scheme::from_str explicitly and include that error in the chain
         let scheme = Scheme::from_str(normalized.scheme()).map_err(|_err| FromStrError {
@@ -197,13 +1124,19 @@ impl GitUrl {
         let urlpath = match &scheme {
             Scheme::Ssh => {
                 // At the moment, we're relying on url::Url's parse() behavior to not duplicate
-                // the leading '/' when we normalize
-                normalized.path()[1..].to_string()
+                // the leading '/' when we normalize. The path is empty for scp-like urls
+                // without one, ex. `git@host:`, which are rejected below
+                let path = normalized.path();
+                path.strip_prefix('/').unwrap_or(path).to_string()
+            }
+            // Requests against smart HTTP endpoints still name the repo they're made to
+            Scheme::Http | Scheme::Https => {
+                route::strip_smart_http_service(normalized.path()).to_string()
             }
             _ => normalized.path().to_string(),
         };
 
-        let git_suffix_check = &urlpath.ends_with(".git");
+        let git_suffix_check = &urlpath.trim_end_matches('/').ends_with(".git");
 
         // Parse through path for name,owner,organization
         // Support organizations for Azure Devops
@@ -216,62 +1149,135 @@ impl GitUrl {
         // name = reponame
         //
         // organizations are going to be supported on a per-host basis
-        let splitpath = &urlpath.rsplit_terminator('/').collect::<Vec<&str>>();
+        let mut splitpath = trim_git_suffix(&urlpath)
+            .rsplit_terminator('/')
+            .filter(|s| !s.is_empty())
+            .collect::<Vec<&str>>();
+
+        // Pasted web UI links (ex. github.com/owner/repo/archive/v1.0.tar.gz) carry the
+        // repo in the segments leading up to the route
+        if let Some(segments) = route::repo_segments(normalized.host_str(), &urlpath) {
+            debug!("Found a web route into: {:?}", segments);
+            splitpath = segments;
+            splitpath.reverse();
+        }
+
+        let splitpath = &splitpath;
         debug!("rsplit results for metadata: {:?}", splitpath);
 
-        let name = splitpath[0].trim_end_matches(".git").to_string();
+        if splitpath.is_empty() {
+            return Err(FromStrError {
+                url: url.to_owned(),
+                kind: FromStrErrorKind::MalformedGitUrl,
+            });
+        }
+
+        let mut name = splitpath[0].trim_end_matches(".git").to_string();
+        let mut groups = Vec::new();
 
         let (owner, organization, fullname) = match &scheme {
-            // We're not going to assume anything about metadata from a filepath
-            Scheme::File => (None::<String>, None::<String>, name.clone()),
+            // We're not going to assume anything about metadata from a filepath, unless asked to
+            // take the parent directory as the owner. A drive (ex. `C:`) is not a directory
+            Scheme::File => match (options.file_owners, splitpath.get(1)) {
+                (true, Some(owner)) if !owner.ends_with(':') => (
+                    Some(owner.to_string()),
+                    None::<String>,
+                    format!("{}/{}", owner, name),
+                ),
+                _ => (None::<String>, None::<String>, name.clone()),
+            },
             _ => {
                 let mut fullname: Vec<&str> = Vec::new();
 
-                // TODO: Add support for parsing out orgs from these urls
-                let hosts_w_organization_in_path = vec!["dev.azure.com", "ssh.dev.azure.com"];
-                //vec!["dev.azure.com", "ssh.dev.azure.com", "visualstudio.com"];
+                let host_str = normalized
+                    .host_str()
+                    .map(host::trim_root_dot)
+                    .ok_or_else(|| FromStrError {
+                        url: url.to_owned(),
+                        kind: FromStrErrorKind::UrlHost,
+                    })?;
 
-                let host_str = normalized.host_str().ok_or_else(|| FromStrError {
-                    url: url.to_owned(),
-                    kind: FromStrErrorKind::UrlHost,
-                })?;
+                // Legacy Azure DevOps hosts carry the organization in their subdomain
+                let visualstudio_organization = host_str
+                    .strip_suffix(".visualstudio.com")
+                    .filter(|o| !o.is_empty() && !o.contains('.'));
 
-                match hosts_w_organization_in_path.contains(&host_str) {
-                    true => {
-                        debug!("Found a git provider with an org");
+                match (
+                    visualstudio_organization,
+                    options.organization_hosts.iter().any(|h| h == host_str),
+                ) {
+                    // Example: "https://CompanyName.visualstudio.com/ProjectName/_git/RepoName",
+                    // Example: "https://CompanyName.visualstudio.com/DefaultCollection/ProjectName/_git/RepoName",
+                    (Some(organization), _) if matches!(scheme, Scheme::Http | Scheme::Https) => {
+                        debug!("Found a visualstudio.com host with an org");
 
-                        // The path differs between git:// and https:// schemes
+                        let marker = match splitpath
+                            .iter()
+                            .position(|s| options.organization_markers.iter().any(|m| m == s))
+                        {
+                            Some(i) if i >= 1 && i + 1 < splitpath.len() => i,
+                            _ => {
+                                return Err(FromStrError {
+                                    url: url.to_owned(),
+                                    kind: FromStrErrorKind::MalformedGitUrl,
+                                });
+                            }
+                        };
+                        name = splitpath[marker - 1].trim_end_matches(".git").to_string();
 
-                        match &scheme {
+                        organization_metadata(organization, splitpath[marker + 1], &name)
+                    }
+                    // Example: "CompanyName@vs-ssh.visualstudio.com:v3/CompanyName/ProjectName/RepoName",
+                    // Example: "git@CompanyName.visualstudio.com:v3/CompanyName/ProjectName/RepoName",
+                    // Over ssh the path names the organization, as on ssh.dev.azure.com
+                    (Some(_), _)
+                        if scheme == Scheme::Ssh
+                            && splitpath.len() == 4
+                            && splitpath[3] == "v3" =>
+                    {
+                        debug!("Found a visualstudio.com ssh host with an org");
+
+                        organization_metadata(splitpath[2], splitpath[1], &name)
+                    }
+                    (_, true) => {
+                        debug!("Found a git provider with an org");
+
+                        // The path differs between git:// and https:// schemes, but both name
+                        // the organization, the project (owner) and the repo
+                        let (organization, owner) = match &scheme {
                             // Example: "git@ssh.dev.azure.com:v3/CompanyName/ProjectName/RepoName",
-                            Scheme::Ssh => {
-                                // Organization
-                                fullname.push(splitpath[2]);
-                                // Project/Owner name
-                                fullname.push(splitpath[1]);
-                                // Repo name
-                                fullname.push(splitpath[0]);
-
-                                (
-                                    Some(splitpath[1].to_string()),
-                                    Some(splitpath[2].to_string()),
-                                    fullname.join("/"),
-                                )
+                            // Some exports leave out the leading `v3` api version segment
+                            Scheme::Ssh
+                                if splitpath.len() == 3
+                                    || (splitpath.len() == 4 && splitpath[3] == "v3") =>
+                            {
+                                (splitpath[2], splitpath[1])
                             }
                             // Example: "https://CompanyName@dev.azure.com/CompanyName/ProjectName/_git/RepoName",
-                            Scheme::Https => {
-                                // Organization
-                                fullname.push(splitpath[3]);
-                                // Project/Owner name
-                                fullname.push(splitpath[2]);
-                                // Repo name
-                                fullname.push(splitpath[0]);
-
-                                (
-                                    Some(splitpath[2].to_string()),
-                                    Some(splitpath[3].to_string()),
-                                    fullname.join("/"),
-                                )
+                            // Example: "https://tfs.corp.com:8080/tfs/Collection/ProjectName/_git/RepoName",
+                            Scheme::Http | Scheme::Https => {
+                                // The repo name follows a marker such as `_git`, preceded by
+                                // the project and the organization (or TFS collection)
+                                let marker = match splitpath.iter().position(|s| {
+                                    options.organization_markers.iter().any(|m| m == s)
+                                }) {
+                                    Some(i) if i >= 1 && i + 2 < splitpath.len() => i,
+                                    _ => {
+                                        return Err(FromStrError {
+                                            url: url.to_owned(),
+                                            kind: FromStrErrorKind::MalformedGitUrl,
+                                        });
+                                    }
+                                };
+                                name = splitpath[marker - 1].trim_end_matches(".git").to_string();
+
+                                (splitpath[marker + 2], splitpath[marker + 1])
+                            }
+                            Scheme::Ssh => {
+                                return Err(FromStrError {
+                                    url: url.to_owned(),
+                                    kind: FromStrErrorKind::MalformedGitUrl,
+                                });
                             }
                             _ => {
                                 return Err(FromStrError {
@@ -279,37 +1285,64 @@ impl GitUrl {
                                     kind: FromStrErrorKind::UnsupportedScheme,
                                 });
                             }
-                        }
+                        };
+
+                        organization_metadata(organization, owner, &name)
                     }
-                    false => {
-                        if !url.starts_with("ssh") && splitpath.len() < 2 {
+                    (_, false) => {
+                        let owner_only = !url.starts_with("ssh") && splitpath.len() < 2;
+                        // The git daemon commonly serves repos from a flat root, ex.
+                        // git://host/reponame
+                        let flat_namespace = splitpath.len() == 1
+                            && (scheme == Scheme::Git
+                                || options.flat_namespace_hosts.iter().any(|h| h == host_str));
+
+                        if flat_namespace {
+                            debug!("Found a repo on a flat namespace host");
+
+                            (None::<String>, None::<String>, name.clone())
+                        } else if owner_only && options.allow_owner_only {
+                            debug!("Found an owner without a repo");
+                            let owner = name;
+                            name = String::new();
+
+                            (Some(owner.clone()), None::<String>, owner)
+                        } else if owner_only {
                             return Err(FromStrError {
                                 url: url.to_owned(),
                                 kind: FromStrErrorKind::MalformedGitUrl,
                             });
-                        }
+                        } else {
+                            let position = match splitpath.len() {
+                                1 => 0,
+                                _ => 1,
+                            };
 
-                        let position = match splitpath.len() {
-                            0 => {
-                                return Err(FromStrError {
-                                    url: url.to_owned(),
-                                    kind: FromStrErrorKind::MalformedGitUrl,
-                                })
-                            }
-                            1 => 0,
-                            _ => 1,
-                        };
+                            // git daemon user-relative paths (git://host/~user/...) are owned
+                            // by the home directory's user, however deep the repo is nested
+                            let owner = match (&scheme, splitpath.last()) {
+                                (Scheme::Git, Some(first)) if first.starts_with('~') => first,
+                                _ => splitpath[position],
+                            };
 
-                        // push owner
-                        fullname.push(splitpath[position]);
-                        // push name
-                        fullname.push(name.as_str());
+                            // Subgroup hosts nest the repo under every group in the path,
+                            // ex. gitlab.com/group/subgroup/repo
+                            if options.subgroup_hosts.iter().any(|h| h == host_str) {
+                                groups = splitpath[position..]
+                                    .iter()
+                                    .rev()
+                                    .map(|s| s.to_string())
+                                    .collect();
+                                fullname.extend(splitpath[position..].iter().rev());
+                            } else {
+                                // push owner
+                                fullname.push(owner);
+                            }
+                            // push name
+                            fullname.push(name.as_str());
 
-                        (
-                            Some(splitpath[position].to_string()),
-                            None::<String>,
-                            fullname.join("/"),
-                        )
+                            (Some(owner.to_string()), None::<String>, fullname.join("/"))
+                        }
                     }
                 }
             }
@@ -317,9 +1350,22 @@ impl GitUrl {
 
         let final_host = match scheme {
             Scheme::File => None,
-            _ => normalized.host_str().map(|h| h.to_string()),
+            _ => normalized.host_str().map(|h| h.to_ascii_lowercase()),
+        };
+
+        let original_host = final_host
+            .as_deref()
+            .and_then(|host| host_as_written(url, host))
+            .filter(|original| Some(*original) != final_host.as_deref())
+            .map(|original| original.to_string());
+
+        let port = match explicit_port(url, &normalized) {
+            Some(port) if options.drop_default_ports && scheme.default_port() == Some(port) => None,
+            port => port,
         };
 
+        let reference = route::reference(normalized.host_str(), &urlpath);
+
         let final_path = match scheme {
             Scheme::File => {
                 if let Some(host) = normalized.host_str() {
@@ -333,41 +1379,117 @@ impl GitUrl {
 
         Ok(GitUrl {
             host: final_host,
-            name,
-            owner,
-            organization,
-            fullname,
+            // The path is split before its segments are decoded, so an encoded `/` stays
+            // within its segment
+            name: decode_component(&name),
+            owner: owner.as_deref().map(decode_component),
+            organization: organization.as_deref().map(decode_component),
+            fullname: decode_component(&fullname),
             scheme,
-            user: match normalized.username().to_string().len() {
-                0 => None,
-                _ => Some(normalized.username().to_string()),
+            user: match (normalized.username().len(), scheme, has_scheme_prefix(url)) {
+                (0, _, _) => None,
+                (_, Scheme::Ssh, false) => scp_user(url).map(|user| user.to_string()),
+                _ => Some(decode_component(normalized.username())),
             },
-            token: normalized.password().map(|p| p.to_string()),
-            port: normalized.port(),
+            token: normalized.password().map(decode_component),
+            port,
             path: final_path,
             git_suffix: *git_suffix_check,
-            scheme_prefix: url.contains("://") || url.starts_with("git:"),
+            scheme_prefix: has_scheme_prefix(url),
+            query: normalized.query().map(|q| q.to_string()),
+            fragment: normalized.fragment().map(|f| f.to_string()),
+            reference,
+            git_short_form: is_git_short_form(url),
+            git_plus_prefix: false,
+            git_prefix: false,
+            original_host,
+            groups: groups.iter().map(|g| decode_component(g)).collect(),
+            worktree: None,
+            comment: None,
         })
     }
 }
 
+/// `organization_metadata` returns the owner, organization and fullname of a repo on an
+/// organization host, whose fullname is `organization/owner/name` whatever the scheme
+fn organization_metadata(
+    organization: &str,
+    owner: &str,
+    name: &str,
+) -> (Option<String>, Option<String>, String) {
+    (
+        Some(owner.to_string()),
+        Some(organization.to_string()),
+        format!("{}/{}/{}", organization, owner, name),
+    )
+}
+
+/// `split_trailing_comment` splits a `#` preceded by whitespace, and everything after it, off
+/// the end of `url`
+fn split_trailing_comment(url: &str) -> (&str, Option<&str>) {
+    let start = url
+        .match_indices('#')
+        .map(|(i, _)| i)
+        .find(|i| url[..*i].ends_with(char::is_whitespace));
+
+    match start {
+        Some(i) => (url[..i].trim_end(), Some(url[i + 1..].trim())),
+        None => (url, None),
+    }
+}
+
+/// `decode_component` percent-decodes a user, token or path segment, which the `url` crate
+/// leaves encoded, ex. `domain%5Cuser` becomes `domain\user`
+fn decode_component(component: &str) -> String {
+    percent_decode_str(component)
+        .decode_utf8_lossy()
+        .to_string()
+}
+
+/// `scp_user` returns the user of the scp-like `url` as written, since git passes it on to ssh
+/// without decoding it
+fn scp_user(url: &str) -> Option<&str> {
+    let authority = url.split(':').next()?;
+    authority.rfind('@').map(|i| &url[..i])
+}
+
+/// `encode_userinfo` reverses `decode_component` so that a user or token is printed as valid
+/// url userinfo
+fn encode_userinfo(userinfo: &str) -> String {
+    utf8_percent_encode(userinfo, convert::USERINFO).to_string()
+}
+
 /// `normalize_ssh_url` takes in an ssh url that separates the login info
 /// from the path into with a `:` and replaces it with `/`.
 ///
 /// Prepends `ssh://` to url
 ///
 /// Supports absolute and relative paths
+///
+/// As in git, the `:` always separates the path and never a port, so `host:1234/owner/repo.git`
+/// has the path `1234/owner/repo.git`. Only the `host:port:path` form carries a port
 fn normalize_ssh_url(url: &str) -> Result<Url, NormalizeUrlError> {
-    let u = url.split(':').collect::<Vec<&str>>();
+    // A leading `@` means the user was left out, ex. `@host:path`
+    if url.starts_with('@') {
+        return Err(NormalizeUrlError {
+            kind: NormalizeUrlErrorKind::EmptySshUser {
+                url: url.to_owned(),
+            },
+        });
+    }
+
+    // Colons within the query or fragment (ex. `?mirror=https://...`) aren't delimiters
+    let (url_path, query) = url.split_at(url.find(['?', '#']).unwrap_or(url.len()));
+    let u = split_outside_brackets(url_path, ':');
 
     match u.len() {
         2 => {
             debug!("Normalizing ssh url: {:?}", u);
-            normalize_url(&format!("ssh://{}/{}", u[0], u[1]))
+            normalize_unquoted_url(&format!("ssh://{}/{}{}", u[0], u[1], query))
         }
         3 => {
             debug!("Normalizing ssh url with ports: {:?}", u);
-            normalize_url(&format!("ssh://{}:{}/{}", u[0], u[1], u[2]))
+            normalize_unquoted_url(&format!("ssh://{}:{}/{}{}", u[0], u[1], u[2], query))
         }
         _default => Err(NormalizeUrlError {
             kind: NormalizeUrlErrorKind::UnsupportedSshPattern {
@@ -377,16 +1499,43 @@ fn normalize_ssh_url(url: &str) -> Result<Url, NormalizeUrlError> {
     }
 }
 
+/// `split_outside_brackets` splits `url` on each `delimiter` that isn't within `[...]`, so the
+/// colons of an IPv6 host literal (ex. `[::1]`) stay in their segment
+fn split_outside_brackets(url: &str, delimiter: char) -> Vec<&str> {
+    let mut segments = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+
+    for (i, c) in url.char_indices() {
+        match c {
+            '[' => depth += 1,
+            ']' => depth = depth.saturating_sub(1),
+            c if c == delimiter && depth == 0 => {
+                segments.push(&url[start..i]);
+                start = i + c.len_utf8();
+            }
+            _ => {}
+        }
+    }
+    segments.push(&url[start..]);
+
+    segments
+}
+
 /// `normalize_file_path` takes in a filepath and uses `Url::from_file_path()` to parse
 ///
 /// Prepends `file://` to url
+///
+/// Spaces end up percent-encoded in the path, and `\` separators become `/`: by
+/// `Url::from_file_path()` for absolute paths on Windows, and by parsing the `file://` url
+/// for relative paths and for Windows paths elsewhere
 #[cfg(any(unix, windows, target_os = "redox", target_os = "wasi"))]
 fn normalize_file_path(filepath: &str) -> Result<Url, NormalizeUrlError> {
     let fp = Url::from_file_path(filepath);
 
     match fp {
         Ok(path) => Ok(path),
-        Err(_e) => normalize_url(&format!("file://{}", filepath)),
+        Err(_e) => normalize_unquoted_url(&format!("file://{}", filepath)),
     }
 }
 
@@ -401,6 +1550,13 @@ pub struct NormalizeUrlError {
     kind: NormalizeUrlErrorKind,
 }
 
+impl NormalizeUrlError {
+    /// Returns the kind of failure encountered while normalizing
+    pub fn kind(&self) -> &NormalizeUrlErrorKind {
+        &self.kind
+    }
+}
+
 impl Display for NormalizeUrlError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match &self.kind {
@@ -410,6 +1566,9 @@ impl Display for NormalizeUrlError {
                 write!(f, "unsupported SSH pattern `{}`", url)
             }
             NormalizeUrlErrorKind::UnsupportedScheme => write!(f, "unsupported URL scheme"),
+            NormalizeUrlErrorKind::EmptySshUser { url } => {
+                write!(f, "empty user before `@` in SSH URL `{}`", url)
+            }
         }
     }
 }
@@ -421,11 +1580,13 @@ impl Error for NormalizeUrlError {
             NormalizeUrlErrorKind::UrlParse(err) => Some(err),
             NormalizeUrlErrorKind::UnsupportedSshPattern { url: _ } => None,
             NormalizeUrlErrorKind::UnsupportedScheme => None,
+            NormalizeUrlErrorKind::EmptySshUser { url: _ } => None,
         }
     }
 }
 
 #[derive(Debug)]
+#[non_exhaustive]
 pub enum NormalizeUrlErrorKind {
     #[non_exhaustive]
     NullBytes,
@@ -435,12 +1596,22 @@ pub enum NormalizeUrlErrorKind {
     UnsupportedSshPattern { url: String },
     #[non_exhaustive]
     UnsupportedScheme,
+    #[non_exhaustive]
+    EmptySshUser { url: String },
 }
 
 /// `normalize_url` takes in url as `&str` and takes an opinionated approach to identify
 /// `ssh://` or `file://` urls that require more information to be added so that
 /// they can be parsed more effectively by `url::Url::parse()`
+///
+/// A single pair of matching quotes around `url` is removed first
 pub fn normalize_url(url: &str) -> Result<Url, NormalizeUrlError> {
+    normalize_unquoted_url(trim_matching_quotes(url))
+}
+
+/// `normalize_unquoted_url` is [`normalize_url`] for a `url` whose quotes, if any, were already
+/// removed
+fn normalize_unquoted_url(url: &str) -> Result<Url, NormalizeUrlError> {
     debug!("Processing: {:?}", &url);
 
     // Error if there are null bytes within the url
@@ -452,13 +1623,25 @@ pub fn normalize_url(url: &str) -> Result<Url, NormalizeUrlError> {
     }
 
     // We're going to remove any trailing slash before running through Url::parse
-    let url = url.trim_end_matches('/');
+    let url = trim_trailing_slashes(url);
+    let url = url.as_str();
+
+    // Windows drive paths (ex. `C:\repo`) would otherwise look like a scheme or scp host
+    if is_windows_drive_path(url) {
+        debug!("Scheme::File match for a Windows drive path");
+        return normalize_file_path(url);
+    }
+
+    // `file:relative/repo` names a path relative to the working directory, which
+    // `Url::parse` would make absolute
+    if let Some(path) = strip_relative_file_scheme(url) {
+        debug!("Scheme::File match for a relative file url");
+        return normalize_file_path(path);
+    }
 
     // Normalize short git url notation: git:host/path.
-    // This is the same as matching Regex::new(r"^git:[^/]")
-    let url_starts_with_git_but_no_slash = url.starts_with("git:") && url.get(4..5) != Some("/");
-    let url_to_parse = if url_starts_with_git_but_no_slash {
-        url.replace("git:", "git://")
+    let url_to_parse = if is_git_short_form(url) {
+        format!("git://{}", &url[4..])
     } else {
         url.to_string()
     };
@@ -473,9 +1656,10 @@ pub fn normalize_url(url: &str) -> Result<Url, NormalizeUrlError> {
         Err(url::ParseError::RelativeUrlWithoutBase) => {
             // If we're here, we're only looking for Scheme::Ssh or Scheme::File
 
-            // Assuming we have found Scheme::Ssh if we can find an "@" before ":"
+            // Assuming we have found Scheme::Ssh if we can find an "@" before ":", or a
+            // hostname before ":" in the scp-like shorthand without a user
             // Otherwise we have Scheme::File
-            match string_contains_asperand_before_colon(url) {
+            match string_contains_asperand_before_colon(url) || is_userless_scp_like(url) {
                 true => {
                     debug!("Scheme::SSH match for normalization");
                     normalize_ssh_url(url)?
@@ -494,6 +1678,189 @@ pub fn normalize_url(url: &str) -> Result<Url, NormalizeUrlError> {
     })
 }
 
+/// `is_fragment_ref` matches a fragment naming a ref, rather than pip's `key=value` pairs
+/// (ex. `egg=name`) or a web UI line anchor (ex. `L5` or `L5-L10`)
+fn is_fragment_ref(fragment: &str) -> bool {
+    let is_line_anchor = matches!(
+        fragment.strip_prefix('L'),
+        Some(rest) if rest.starts_with(|c: char| c.is_ascii_digit())
+            && rest.chars().all(|c| c.is_ascii_digit() || matches!(c, '-' | 'L' | 'C'))
+    );
+
+    !fragment.is_empty() && !fragment.contains('=') && !is_line_anchor
+}
+
+/// `split_at_ref` splits a trailing `@ref` off the path of `url`, ex. `repo.git@v1`, keeping
+/// any query or fragment. An `@` within the authority separates the user, and is left alone
+fn split_at_ref(url: &str) -> (String, Option<String>) {
+    let path_start = match url.find("://") {
+        Some(i) => url[i + 3..].find('/').map_or(url.len(), |j| i + 3 + j),
+        // scp-like urls start their path after the first `:`
+        None => url.find(':').map_or(0, |i| i + 1),
+    };
+    let path_end = url.find(['?', '#']).unwrap_or(url.len()).max(path_start);
+
+    match url[path_start..path_end].rfind('@') {
+        Some(i) if path_start + i + 1 < path_end => {
+            let at = path_start + i;
+            (
+                format!("{}{}", &url[..at], &url[path_end..]),
+                Some(url[at + 1..path_end].to_string()),
+            )
+        }
+        _ => (url.to_string(), None),
+    }
+}
+
+/// `trim_git_suffix` removes the `.git` suffix of a repo path, ex. `owner/repo` for
+/// `owner/repo.git/`, along with trailing slashes and repeated suffixes, such as
+/// `repo.git.git` or the `.git` directory of `repo/.git`
+pub fn trim_git_suffix(path: &str) -> &str {
+    let mut path = path;
+    loop {
+        let trimmed = path.trim_end_matches('/');
+        let trimmed = trimmed.strip_suffix(".git").unwrap_or(trimmed);
+        if trimmed.len() == path.len() {
+            return path;
+        }
+        path = trimmed;
+    }
+}
+
+/// `trim_matching_quotes` removes a single pair of matching single or double quotes around
+/// `url`, as retained by some CI environment variables. Quotes inside the url are kept
+fn trim_matching_quotes(url: &str) -> &str {
+    ['"', '\'']
+        .iter()
+        .find_map(|quote| url.strip_prefix(*quote)?.strip_suffix(*quote))
+        .unwrap_or(url)
+}
+
+/// `host_as_written` returns `host` as it is written in the authority of `url`, before
+/// `url::Url` lowercased it
+fn host_as_written<'a>(url: &'a str, host: &str) -> Option<&'a str> {
+    let start = url.find("://").map_or(0, |i| i + 3);
+    let authority_end = url[start..].find('/').map_or(url.len(), |i| start + i);
+    let start = url[start..authority_end]
+        .rfind('@')
+        .map_or(start, |i| start + i + 1);
+
+    url.get(start..start + host.len())
+        .filter(|original| original.eq_ignore_ascii_case(host))
+}
+
+/// `explicit_port` returns the port written in `url`
+///
+/// `url::Url` discards a port matching the default of special schemes (e.g. `:443` for
+/// `https://`), so it is recovered from the authority of the original input
+fn explicit_port(url: &str, normalized: &Url) -> Option<u16> {
+    if normalized.port().is_some() {
+        return normalized.port();
+    }
+
+    let default_port = normalized.port_or_known_default()?;
+    let (_scheme, rest) = url.split_once("://")?;
+    let authority = rest.split(['/', '?', '#']).next()?;
+    let host_and_port = authority.rsplit('@').next()?;
+    let (_host, port) = host_and_port.rsplit_once(':')?;
+
+    match port.parse::<u16>() {
+        Ok(port) if port == default_port => Some(port),
+        _ => None,
+    }
+}
+
+/// `has_scheme_prefix` checks whether `url` was written with a scheme, either as `scheme://` or
+/// the `git:host` short form. A `://` later in the url, ex. in the query of an scp url, or a
+/// `git@` user does not count
+fn has_scheme_prefix(url: &str) -> bool {
+    let is_scheme = |scheme: &str| {
+        !scheme.is_empty()
+            && scheme
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'))
+    };
+
+    match url.split_once("://") {
+        Some((scheme, _)) if is_scheme(scheme) => true,
+        _ => is_git_short_form(url),
+    }
+}
+
+/// This is the same as matching Regex::new(r"(?i)^git:[^/]")
+fn is_git_short_form(url: &str) -> bool {
+    matches!(url.get(..4), Some(scheme) if scheme.eq_ignore_ascii_case("git:"))
+        && url.get(4..5) != Some("/")
+}
+
+/// `trim_trailing_slashes` removes every trailing `/` from the path portion of `url`
+/// in a single pass, so `repo.git///` and `repo.git/?depth=1` both end in `repo.git`
+fn trim_trailing_slashes(url: &str) -> String {
+    let path_end = url.find(['?', '#']).unwrap_or(url.len());
+    let (path, rest) = url.split_at(path_end);
+
+    format!("{}{}", path.trim_end_matches('/'), rest)
+}
+
+/// `strip_relative_file_scheme` returns the path of a `file:` url without a `/` after the
+/// scheme, ex. `relative/repo` for `file:relative/repo`
+fn strip_relative_file_scheme(url: &str) -> Option<&str> {
+    let scheme = url.get(..5)?;
+    let path = &url[5..];
+
+    match scheme.eq_ignore_ascii_case("file:") && !path.is_empty() && !path.starts_with('/') {
+        true => Some(path),
+        false => None,
+    }
+}
+
+/// `relative_file_path` returns the local path of `url` when it is relative to the working
+/// directory, written either bare (ex. `../repo.git`) or as a `file:` url without a `/`
+fn relative_file_path(url: &str) -> Option<&str> {
+    if url.contains('\0') || is_windows_drive_path(url) {
+        return None;
+    }
+
+    if let Some(path) = strip_relative_file_scheme(url) {
+        return Some(path);
+    }
+
+    let is_relative = !url.starts_with(['/', '\\'])
+        && !is_git_short_form(url)
+        && !string_contains_asperand_before_colon(url)
+        && !is_userless_scp_like(url)
+        && Url::parse(url) == Err(url::ParseError::RelativeUrlWithoutBase);
+
+    match is_relative {
+        true => Some(url),
+        false => None,
+    }
+}
+
+/// `is_windows_drive_path` matches a drive letter followed by `:` and a separator (or
+/// nothing), ex. `C:\repo`
+fn is_windows_drive_path(url: &str) -> bool {
+    let bytes = url.as_bytes();
+
+    bytes.len() >= 2
+        && bytes[0].is_ascii_alphabetic()
+        && bytes[1] == b':'
+        && matches!(bytes.get(2), None | Some(b'\\') | Some(b'/'))
+}
+
+/// `is_userless_scp_like` matches the scp-like `host:path` shorthand without a user, where the
+/// host looks like a hostname (ex. `github.com:owner/repo.git`) and the path is relative
+fn is_userless_scp_like(url: &str) -> bool {
+    match url.split_once(':') {
+        Some((host, path)) => {
+            !host.contains(['/', '\\'])
+                && (host.contains('.') || host == "localhost")
+                && !path.starts_with(['/', '\\'])
+        }
+        None => false,
+    }
+}
+
 /// This is the same as matching Regex::new(r"^\S+(@)\S+(:).*$");
 fn string_contains_asperand_before_colon(str: &str) -> bool {
     let index_of_asperand = str.find('@');
@@ -0,0 +1,63 @@
+/// Opt-in parsing behaviors for [`GitUrl::parse_with_options`](crate::GitUrl::parse_with_options)
+///
+/// Every option defaults to the behavior of [`GitUrl::parse`](crate::GitUrl::parse). Options
+/// are added over time, so start from `ParseOptions::default()` and set the fields to change
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub struct ParseOptions {
+    /// Split a trailing ` -- <worktree>` hint off local file paths and record it in
+    /// `GitUrl::worktree`
+    pub worktree_hints: bool,
+    /// Split a trailing ` # comment`, as found in annotated mirror lists, off the url and
+    /// record it in `GitUrl::comment`. The `#` must be preceded by whitespace
+    pub trailing_comments: bool,
+    /// Drop an explicit port matching the scheme's default (e.g. `:443` for `https://`)
+    pub drop_default_ports: bool,
+    /// Accept urls naming only an account (ex. `https://github.com/owner`), populating `owner`
+    /// and leaving `name` empty
+    pub allow_owner_only: bool,
+    /// Treat a lone userinfo with a GitHub token prefix (`ghp_`, `gho_` or `github_pat_`), as in
+    /// `https://ghp_xxx@github.com/owner/repo.git`, as the `token` rather than the `user`
+    pub detect_github_tokens: bool,
+    /// Hosts that carry an organization in the path, as Azure DevOps does
+    ///
+    /// Defaults to the Azure DevOps cloud hosts. Add Azure DevOps Server (TFS) hosts here,
+    /// where the collection is parsed as the organization
+    pub organization_hosts: Vec<String>,
+    /// Path segments that precede the repo name in https urls on organization hosts
+    ///
+    /// Defaults to Azure DevOps' `_git`. Some exports use other markers, such as `_ssh`
+    pub organization_markers: Vec<String>,
+    /// Hosts that nest repos under any number of groups, as GitLab does, populating
+    /// `GitUrl::groups` and including every group in `fullname`
+    ///
+    /// Defaults to `gitlab.com`. Other hosts only take the repo's direct parent as its owner
+    pub subgroup_hosts: Vec<String>,
+    /// Hosts without owners, where a single path segment (ex. a gist id) names the repo
+    /// and `owner` is left empty
+    ///
+    /// Defaults to GitHub Gist's `gist.github.com`
+    pub flat_namespace_hosts: Vec<String>,
+    /// Take the directory containing a local repo as its `owner`, ex. `team` for
+    /// `/srv/git/team/repo.git`, making `fullname` `owner/name`
+    ///
+    /// By default file urls have no owner, and `fullname` is the name
+    pub file_owners: bool,
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        ParseOptions {
+            worktree_hints: false,
+            trailing_comments: false,
+            drop_default_ports: false,
+            allow_owner_only: false,
+            detect_github_tokens: false,
+            organization_hosts: vec!["dev.azure.com".to_string(), "ssh.dev.azure.com".to_string()],
+            organization_markers: vec!["_git".to_string()],
+            subgroup_hosts: vec!["gitlab.com".to_string()],
+            flat_namespace_hosts: vec!["gist.github.com".to_string()],
+            file_owners: false,
+        }
+    }
+}
@@ -0,0 +1,101 @@
+use crate::GitUrl;
+
+/// Git hosting services, as identified by [`GitUrl::provider`]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Provider {
+    /// Represents github.com
+    GitHub,
+    /// Represents gitlab.com
+    GitLab,
+    /// Represents bitbucket.org
+    Bitbucket,
+    /// Represents Azure DevOps (dev.azure.com)
+    AzureDevOps,
+    /// Represents SourceHut (git.sr.ht)
+    SourceHut,
+    /// Represents Gitea and its forks, as hosted on gitea.com and codeberg.org
+    Gitea,
+    /// Represents any other host, including self-hosted instances of the above
+    Unknown,
+}
+
+/// Host of GitHub Gist remotes
+const GIST_HOST: &str = "gist.github.com";
+
+/// Hosts of each provider's public service
+const PROVIDER_HOSTS: &[(&str, Provider)] = &[
+    ("github.com", Provider::GitHub),
+    ("ssh.github.com", Provider::GitHub),
+    ("gist.github.com", Provider::GitHub),
+    ("gitlab.com", Provider::GitLab),
+    ("bitbucket.org", Provider::Bitbucket),
+    ("dev.azure.com", Provider::AzureDevOps),
+    ("ssh.dev.azure.com", Provider::AzureDevOps),
+    ("git.sr.ht", Provider::SourceHut),
+    ("gitea.com", Provider::Gitea),
+    ("codeberg.org", Provider::Gitea),
+];
+
+impl Provider {
+    /// Indicate if the service treats owner and repo names case-insensitively
+    pub fn is_case_insensitive(&self) -> bool {
+        match self {
+            Provider::GitHub | Provider::GitLab | Provider::Bitbucket | Provider::AzureDevOps => {
+                true
+            }
+            Provider::SourceHut | Provider::Gitea | Provider::Unknown => false,
+        }
+    }
+}
+
+impl GitUrl {
+    /// Identifies the hosting service from `host`
+    ///
+    /// Only the public services are recognized, so self-hosted instances (ex.
+    /// `gitlab.example.com`) are `Unknown`. See [`GitUrl::provider_with_overrides`]
+    pub fn provider(&self) -> Provider {
+        self.provider_with_overrides(&[])
+    }
+
+    /// Identifies the hosting service from `host`, consulting the `(host, provider)` pairs of
+    /// `overrides` (ex. `("gitlab.example.com", Provider::GitLab)`) before the public services
+    pub fn provider_with_overrides(&self, overrides: &[(&str, Provider)]) -> Provider {
+        let host = match self.canonical_host() {
+            Some(host) => host,
+            None => return Provider::Unknown,
+        };
+
+        overrides
+            .iter()
+            .chain(PROVIDER_HOSTS)
+            .find(|(h, _)| h.eq_ignore_ascii_case(host))
+            .map_or(Provider::Unknown, |(_, provider)| *provider)
+    }
+
+    /// Indicate if the url is a GitHub Gist remote (`gist.github.com`), whose `name` is the
+    /// gist id and `owner` is only set when the url names the gist's user
+    pub fn is_gist(&self) -> bool {
+        match self.canonical_host() {
+            Some(host) => host.eq_ignore_ascii_case(GIST_HOST),
+            None => false,
+        }
+    }
+
+    /// Returns a `GitUrl` with a lowercase `host`, and lowercase `organization`, `groups`,
+    /// `owner` and `name` on providers that ignore their case. `fullname` is recomputed, while
+    /// `path` is left as is
+    pub fn normalize_case(&self) -> GitUrl {
+        let mut new_giturl = self.clone();
+        new_giturl.host = self.host.as_ref().map(|host| host.to_lowercase());
+
+        if self.provider().is_case_insensitive() {
+            new_giturl.organization = self.organization.as_ref().map(|o| o.to_lowercase());
+            new_giturl.groups = self.groups.iter().map(|g| g.to_lowercase()).collect();
+            new_giturl.owner = self.owner.as_ref().map(|o| o.to_lowercase());
+            new_giturl.name = self.name.to_lowercase();
+            new_giturl.fullname = new_giturl.joined_fullname();
+        }
+
+        new_giturl
+    }
+}
@@ -0,0 +1,114 @@
+use percent_encoding::percent_decode_str;
+
+use crate::host::trim_root_dot;
+
+/// Hosts whose web UI routes are recognized in pasted browser links
+const GITHUB_HOSTS: &[&str] = &["github.com"];
+
+/// Route segments that can follow `owner/repo` on github hosts
+const GITHUB_ROUTES: &[&str] = &["archive", "releases", "tree"];
+
+/// Repo page tabs that can follow `owner/repo` on github hosts
+pub(crate) const GITHUB_TABS: &[&str] = &["pulls", "issues", "actions", "wiki", "settings"];
+
+/// Hosts whose `blob/HEAD` and `raw/HEAD` file links are recognized
+const HEAD_HOSTS: &[&str] = &["github.com", "gitlab.com"];
+
+/// Route segments that are only recognized when followed by the `HEAD` sentinel
+const HEAD_ROUTES: &[&str] = &["blob", "raw"];
+
+/// Smart HTTP endpoints that git requests below the repo path
+const SMART_HTTP_SERVICES: &[&str] = &[
+    "/info/refs",
+    "/git-upload-pack",
+    "/git-receive-pack",
+    "/git-upload-archive",
+];
+
+/// `strip_smart_http_service` removes a trailing smart HTTP endpoint, ex. `/info/refs` in
+/// `/owner/repo.git/info/refs`, leaving the path to the repo
+pub(crate) fn strip_smart_http_service(path: &str) -> &str {
+    SMART_HTTP_SERVICES
+        .iter()
+        .find_map(|service| path.strip_suffix(service))
+        .unwrap_or(path)
+}
+
+/// `web_route` returns the route segment when `path` continues past the repo into a known web
+/// UI route on `host`, e.g. `archive` for `/owner/repo/archive/v1.0.tar.gz`
+pub(crate) fn web_route<'a>(host: Option<&str>, path: &'a str) -> Option<&'a str> {
+    route_position(host, path).map(|(_, route)| route)
+}
+
+/// `repo_segments` returns the segments of `path` naming the repo a web UI link points into,
+/// e.g. `group/subgroup/repo` for `/group/subgroup/repo/-/blob/HEAD/README.md`
+pub(crate) fn repo_segments<'a>(host: Option<&str>, path: &'a str) -> Option<Vec<&'a str>> {
+    let (len, _) = route_position(host, path)?;
+
+    Some(
+        path.split('/')
+            .filter(|s| !s.is_empty())
+            .take(len)
+            .collect(),
+    )
+}
+
+/// `route_position` returns the number of segments naming the repo, followed by the route
+/// segment, when `path` continues into a known web UI route on `host`
+///
+/// github routes always follow `owner/repo`. `blob/HEAD` and `raw/HEAD` links are found by
+/// scanning, as gitlab nests repos under subgroups and may separate the route with a `-`
+fn route_position<'a>(host: Option<&str>, path: &'a str) -> Option<(usize, &'a str)> {
+    let host = trim_root_dot(host?);
+    let segments = path
+        .split('/')
+        .filter(|s| !s.is_empty())
+        .collect::<Vec<_>>();
+    let route = *segments.get(2)?;
+
+    if GITHUB_HOSTS.contains(&host)
+        && (GITHUB_ROUTES.contains(&route) || GITHUB_TABS.contains(&route))
+    {
+        return Some((2, route));
+    }
+
+    if !HEAD_HOSTS.contains(&host) {
+        return None;
+    }
+
+    let position = (2..segments.len())
+        .find(|&i| HEAD_ROUTES.contains(&segments[i]) && segments.get(i + 1) == Some(&"HEAD"))?;
+
+    match segments[position - 1] {
+        "-" if position > 2 => Some((position - 1, segments[position])),
+        "-" => None,
+        _ => Some((position, segments[position])),
+    }
+}
+
+/// `reference` returns the percent-decoded ref a web UI link points at, e.g. `feature/x` for
+/// `/owner/repo/tree/feature/x`, `v1.0` for `/owner/repo/releases/tag/v1.0`, or `HEAD` for
+/// `/owner/repo/blob/HEAD/README.md`
+///
+/// Everything after `tree/` is taken as the ref so that branch names containing `/` survive,
+/// which means a trailing path into the tree is indistinguishable from the ref
+pub(crate) fn reference(host: Option<&str>, path: &str) -> Option<String> {
+    let skip = match web_route(host, path)? {
+        "tree" => 3,
+        "releases" if path.split('/').filter(|s| !s.is_empty()).nth(3) == Some("tag") => 4,
+        route if HEAD_ROUTES.contains(&route) => return Some("HEAD".to_string()),
+        _ => return None,
+    };
+
+    let segments = path
+        .split('/')
+        .filter(|s| !s.is_empty())
+        .skip(skip)
+        .map(|s| percent_decode_str(s).decode_utf8_lossy())
+        .collect::<Vec<_>>();
+
+    match segments.is_empty() {
+        true => None,
+        false => Some(segments.join("/")),
+    }
+}
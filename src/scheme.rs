@@ -5,7 +5,7 @@ use std::{
 };
 
 /// Supported URI schemes for parsing
-#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
 pub enum Scheme {
     /// Represents `file://` url scheme
     File,
@@ -21,6 +21,8 @@ pub enum Scheme {
     Http,
     /// Represents `https://` url scheme
     Https,
+    /// Represents `rsync://` url scheme
+    Rsync,
     /// Represents `ssh://` url scheme
     Ssh,
     /// Represents No url scheme
@@ -37,12 +39,63 @@ impl Display for Scheme {
             Scheme::GitSsh => write!(f, "git+ssh"),
             Scheme::Http => write!(f, "http"),
             Scheme::Https => write!(f, "https"),
+            Scheme::Rsync => write!(f, "rsync"),
             Scheme::Ssh => write!(f, "ssh"),
             Scheme::Unspecified => write!(f, "unspecified"),
         }
     }
 }
 
+impl Scheme {
+    /// Returns the port conventionally used by the scheme, if any
+    pub fn default_port(&self) -> Option<u16> {
+        match self {
+            Scheme::Ftp => Some(21),
+            Scheme::Ftps => Some(990),
+            Scheme::Git => Some(9418),
+            Scheme::GitSsh | Scheme::Ssh => Some(22),
+            Scheme::Http => Some(80),
+            Scheme::Https => Some(443),
+            Scheme::Rsync => Some(873),
+            Scheme::File | Scheme::Unspecified => None,
+        }
+    }
+
+    /// Indicate if urls of the scheme are only valid when spelled with their scheme prefix
+    ///
+    /// `ssh` urls have the scp-like form and local paths need no `file://`, but every other
+    /// scheme does
+    pub fn requires_prefix(&self) -> bool {
+        match self {
+            Scheme::Ftp
+            | Scheme::Ftps
+            | Scheme::Git
+            | Scheme::GitSsh
+            | Scheme::Http
+            | Scheme::Https
+            | Scheme::Rsync => true,
+            Scheme::File | Scheme::Ssh | Scheme::Unspecified => false,
+        }
+    }
+
+    /// Returns the scheme conventionally served on the well-known `port`, if any.
+    /// The inverse of [`Scheme::default_port`]
+    ///
+    /// Intended as a heuristic for inputs that carry an explicit port but no scheme
+    pub fn from_port(port: u16) -> Option<Scheme> {
+        match port {
+            21 => Some(Scheme::Ftp),
+            22 => Some(Scheme::Ssh),
+            80 => Some(Scheme::Http),
+            443 => Some(Scheme::Https),
+            873 => Some(Scheme::Rsync),
+            990 => Some(Scheme::Ftps),
+            9418 => Some(Scheme::Git),
+            _ => None,
+        }
+    }
+}
+
 #[derive(Debug)]
 #[non_exhaustive]
 pub struct FromStrError {
@@ -76,8 +129,9 @@ pub enum FromStrErrorKind {
 impl FromStr for Scheme {
     type Err = FromStrError;
 
+    /// Schemes are matched case-insensitively, as specified by RFC 3986
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s {
+        match s.to_ascii_lowercase().as_str() {
             "file" => Ok(Scheme::File),
             "ftp" => Ok(Scheme::Ftp),
             "ftps" => Ok(Scheme::Ftps),
@@ -85,6 +139,7 @@ impl FromStr for Scheme {
             "git+ssh" => Ok(Scheme::GitSsh),
             "http" => Ok(Scheme::Http),
             "https" => Ok(Scheme::Https),
+            "rsync" => Ok(Scheme::Rsync),
             "ssh" => Ok(Scheme::Ssh),
             "unspecified" => Ok(Scheme::Unspecified),
             _ => Err(FromStrError {
@@ -0,0 +1,85 @@
+use std::{
+    error::Error,
+    fmt::{self, Display},
+};
+
+use crate::{GitUrl, Scheme};
+
+impl GitUrl {
+    /// Checks the invariants between the fields of a (possibly hand-built) `GitUrl`, reporting
+    /// the first one that does not hold
+    ///
+    /// - every scheme but `file` has a host
+    /// - `fullname` is made up of `organization`, `owner` and `name`
+    /// - schemes that require their prefix have `scheme_prefix` set
+    /// - the port is non-zero, and only set alongside a host
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        if self.scheme != Scheme::File && self.host.is_none() {
+            return Err(ValidationError {
+                kind: ValidationErrorKind::MissingHost,
+            });
+        }
+
+        let expected = self.joined_fullname();
+        if self.fullname != expected {
+            return Err(ValidationError {
+                kind: ValidationErrorKind::FullnameMismatch { expected },
+            });
+        }
+
+        if self.scheme.requires_prefix() && !self.scheme_prefix {
+            return Err(ValidationError {
+                kind: ValidationErrorKind::MissingSchemePrefix(self.scheme),
+            });
+        }
+
+        match self.port {
+            Some(port) if port == 0 || self.host.is_none() => Err(ValidationError {
+                kind: ValidationErrorKind::InvalidPort(port),
+            }),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct ValidationError {
+    kind: ValidationErrorKind,
+}
+
+impl ValidationError {
+    /// Returns the kind of invariant found not to hold
+    pub fn kind(&self) -> &ValidationErrorKind {
+        &self.kind
+    }
+}
+
+impl Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            ValidationErrorKind::MissingHost => write!(f, "URL has no host"),
+            ValidationErrorKind::FullnameMismatch { expected } => {
+                write!(f, "fullname does not match `{}`", expected)
+            }
+            ValidationErrorKind::MissingSchemePrefix(scheme) => {
+                write!(f, "scheme `{}` requires its prefix", scheme)
+            }
+            ValidationErrorKind::InvalidPort(port) => write!(f, "invalid port `{}`", port),
+        }
+    }
+}
+
+impl Error for ValidationError {}
+
+#[derive(Debug)]
+pub enum ValidationErrorKind {
+    #[non_exhaustive]
+    MissingHost,
+    #[non_exhaustive]
+    FullnameMismatch { expected: String },
+    #[non_exhaustive]
+    MissingSchemePrefix(Scheme),
+    #[non_exhaustive]
+    InvalidPort(u16),
+}
@@ -0,0 +1,242 @@
+use parse_git_url::*;
+
+#[test]
+fn with_organization_https() {
+    let git_url = GitUrl {
+        host: Some("dev.azure.com".to_string()),
+        name: "repo".to_string(),
+        owner: Some("project".to_string()),
+        scheme: Scheme::Https,
+        scheme_prefix: true,
+        ..Default::default()
+    }
+    .with_organization("organization");
+
+    assert_eq!(git_url.organization, Some("organization".to_string()));
+    assert_eq!(git_url.fullname, "organization/project/repo");
+    assert_eq!(
+        format!("{}", git_url),
+        "https://dev.azure.com/organization/project/_git/repo"
+    );
+}
+
+#[test]
+fn with_organization_matches_parse() {
+    let test_url = "https://dev.azure.com/organization/project/_git/repo";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+    assert_eq!(parsed.with_organization("organization"), parsed);
+}
+
+#[test]
+fn with_organization_ssh() {
+    let git_url = GitUrl {
+        host: Some("ssh.dev.azure.com".to_string()),
+        name: "repo".to_string(),
+        owner: Some("project".to_string()),
+        scheme: Scheme::Ssh,
+        user: Some("git".to_string()),
+        ..Default::default()
+    }
+    .with_organization("organization");
+
+    assert_eq!(git_url.fullname, "organization/project/repo");
+    assert_eq!(
+        format!("{}", git_url),
+        "git@ssh.dev.azure.com:v3/organization/project/repo"
+    );
+}
+
+#[test]
+fn https_azure_devops_server_custom_port() {
+    let test_url = "https://tfs.corp.com:8080/tfs/Collection/Project/_git/Repo";
+    let mut options = ParseOptions::default();
+    options.organization_hosts = vec!["tfs.corp.com".to_string()];
+    let parsed = GitUrl::parse_with_options(test_url, &options).expect("URL parse failed");
+    let expected = GitUrl {
+        host: Some("tfs.corp.com".to_string()),
+        name: "Repo".to_string(),
+        owner: Some("Project".to_string()),
+        organization: Some("Collection".to_string()),
+        fullname: "Collection/Project/Repo".to_string(),
+        scheme: Scheme::Https,
+        user: None,
+        token: None,
+        port: Some(8080),
+        path: "/tfs/Collection/Project/_git/Repo".to_string(),
+        git_suffix: false,
+        scheme_prefix: true,
+        query: None,
+        fragment: None,
+        reference: None,
+        git_short_form: false,
+        git_plus_prefix: false,
+        git_prefix: false,
+        original_host: None,
+        groups: Vec::new(),
+        worktree: None,
+        comment: None,
+    };
+
+    assert_eq!(parsed, expected);
+    assert_eq!(format!("{}", parsed), test_url);
+}
+
+#[test]
+fn https_azure_devops_server_not_configured() {
+    let test_url = "https://tfs.corp.com:8080/tfs/Collection/Project/_git/Repo";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+    assert_eq!(parsed.organization, None);
+    assert_eq!(parsed.owner, Some("_git".to_string()));
+}
+
+#[test]
+fn https_azure_devops_missing_marker() {
+    let test_url = "https://dev.azure.com/organization/project/repo";
+    let e = GitUrl::parse(test_url);
+
+    assert!(e.is_err());
+}
+
+#[test]
+fn https_azure_devops_default_marker() {
+    let test_url = "https://dev.azure.com/organization/project/_git/repo";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+    assert_eq!(parsed.organization, Some("organization".to_string()));
+    assert_eq!(parsed.owner, Some("project".to_string()));
+    assert_eq!(parsed.name, "repo");
+}
+
+#[test]
+fn https_azure_devops_custom_marker() {
+    let test_url = "https://dev.azure.com/organization/project/_ssh/repo";
+    let mut options = ParseOptions::default();
+    options.organization_markers = vec!["_git".to_string(), "_ssh".to_string()];
+    let parsed = GitUrl::parse_with_options(test_url, &options).expect("URL parse failed");
+
+    assert_eq!(parsed.organization, Some("organization".to_string()));
+    assert_eq!(parsed.owner, Some("project".to_string()));
+    assert_eq!(parsed.name, "repo");
+    assert_eq!(parsed.fullname, "organization/project/repo");
+}
+
+#[test]
+fn https_azure_devops_custom_marker_not_configured() {
+    let test_url = "https://dev.azure.com/organization/project/_ssh/repo";
+    let e = GitUrl::parse(test_url);
+
+    assert!(e.is_err());
+}
+
+#[test]
+fn ssh_azure_devops_v3() {
+    let test_url = "git@ssh.dev.azure.com:v3/organization/project/repo";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+    assert_eq!(parsed.organization, Some("organization".to_string()));
+    assert_eq!(parsed.owner, Some("project".to_string()));
+    assert_eq!(parsed.name, "repo");
+    assert_eq!(parsed.fullname, "organization/project/repo");
+}
+
+#[test]
+fn ssh_azure_devops_without_v3() {
+    let test_url = "git@ssh.dev.azure.com:organization/project/repo";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+    assert_eq!(parsed.organization, Some("organization".to_string()));
+    assert_eq!(parsed.owner, Some("project".to_string()));
+    assert_eq!(parsed.name, "repo");
+    assert_eq!(parsed.fullname, "organization/project/repo");
+    assert_eq!(parsed.to_string(), test_url);
+}
+
+#[test]
+fn ssh_azure_devops_extra_segment() {
+    let test_url = "git@ssh.dev.azure.com:v2/organization/project/repo";
+    let e = GitUrl::parse(test_url);
+
+    assert!(e.is_err());
+}
+
+#[test]
+fn visualstudio_https() {
+    let test_url = "https://companyname.visualstudio.com/project/_git/repo";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+    let expected = GitUrl {
+        host: Some("companyname.visualstudio.com".to_string()),
+        name: "repo".to_string(),
+        owner: Some("project".to_string()),
+        organization: Some("companyname".to_string()),
+        fullname: "companyname/project/repo".to_string(),
+        scheme: Scheme::Https,
+        user: None,
+        token: None,
+        port: None,
+        path: "/project/_git/repo".to_string(),
+        git_suffix: false,
+        scheme_prefix: true,
+        query: None,
+        fragment: None,
+        reference: None,
+        git_short_form: false,
+        git_plus_prefix: false,
+        git_prefix: false,
+        original_host: None,
+        groups: Vec::new(),
+        worktree: None,
+        comment: None,
+    };
+
+    assert_eq!(parsed, expected);
+    assert_eq!(parsed.to_string(), test_url);
+}
+
+#[test]
+fn visualstudio_default_collection() {
+    let test_url = "https://companyname.visualstudio.com/DefaultCollection/project/_git/repo.git";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+    assert_eq!(parsed.organization, Some("companyname".to_string()));
+    assert_eq!(parsed.owner, Some("project".to_string()));
+    assert_eq!(parsed.name, "repo");
+    assert_eq!(parsed.fullname, "companyname/project/repo");
+    assert_eq!(parsed.to_string(), test_url);
+}
+
+#[test]
+fn visualstudio_without_marker() {
+    let test_url = "https://companyname.visualstudio.com/project/repo";
+    let e = GitUrl::parse(test_url).expect_err("URL parse should fail");
+
+    assert!(matches!(e.kind(), FromStrErrorKind::MalformedGitUrl { .. }));
+}
+
+#[test]
+fn fullname_same_across_schemes() {
+    let test_urls = [
+        "git@ssh.dev.azure.com:v3/organization/project/repo",
+        "ssh://git@ssh.dev.azure.com/v3/organization/project/repo",
+        "https://organization@dev.azure.com/organization/project/_git/repo",
+        "https://dev.azure.com/organization/project/_git/repo.git",
+        "https://organization.visualstudio.com/project/_git/repo",
+        "organization@vs-ssh.visualstudio.com:v3/organization/project/repo",
+        "git@organization.visualstudio.com:v3/organization/project/repo",
+    ];
+
+    for test_url in test_urls {
+        let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+        assert_eq!(parsed.fullname, "organization/project/repo", "{}", test_url);
+        assert_eq!(parsed.owner, Some("project".to_string()), "{}", test_url);
+        assert_eq!(
+            parsed.organization,
+            Some("organization".to_string()),
+            "{}",
+            test_url
+        );
+        assert_eq!(parsed.name, "repo", "{}", test_url);
+    }
+}
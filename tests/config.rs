@@ -0,0 +1,78 @@
+use parse_git_url::*;
+
+const CONFIG: &str = r#"[core]
+	repositoryformatversion = 0
+	filemode = true
+	bare = false
+	logallrefupdates = true
+[remote "upstream"]
+	url = https://github.com/upstream/repo.git
+	fetch = +refs/heads/*:refs/remotes/upstream/*
+[remote "origin"]
+	url = git@github.com:owner/repo.git
+	fetch = +refs/heads/*:refs/remotes/origin/*
+[branch "main"]
+	remote = origin
+	merge = refs/heads/main
+"#;
+
+#[test]
+fn origin() {
+    let parsed = GitUrl::parse_from_git_config(CONFIG, "origin").expect("remote lookup failed");
+    let expected = GitUrl::parse("git@github.com:owner/repo.git").expect("URL parse failed");
+
+    assert_eq!(parsed, expected);
+}
+
+#[test]
+fn other_remote() {
+    let parsed = GitUrl::parse_from_git_config(CONFIG, "upstream").expect("remote lookup failed");
+
+    assert_eq!(parsed.owner, Some("upstream".to_string()));
+    assert_eq!(parsed.name, "repo");
+}
+
+#[test]
+fn missing_remote() {
+    let e = GitUrl::parse_from_git_config(CONFIG, "fork").expect_err("remote should be missing");
+
+    assert!(matches!(
+        e.kind(),
+        GitConfigErrorKind::MissingRemote { remote, .. } if remote == "fork"
+    ));
+}
+
+#[test]
+fn unparseable_remote_url() {
+    let config = "[remote \"origin\"]\n\turl = https://github.com:owner/repo.git\n";
+    let e = GitUrl::parse_from_git_config(config, "origin").expect_err("url should not parse");
+
+    assert!(matches!(e.kind(), GitConfigErrorKind::ParseUrl { .. }));
+}
+
+#[test]
+fn header_with_comment() {
+    let config = "[remote \"origin\"] # the fork\n\turl = https://github.com/owner/repo.git\n";
+    let parsed = GitUrl::parse_from_git_config(config, "origin").expect("remote lookup failed");
+
+    assert_eq!(parsed.fullname, "owner/repo");
+}
+
+#[test]
+fn url_with_inline_comment() {
+    let config = "[remote \"origin\"]\n\turl = git@github.com:owner/repo.git ; ssh\n\n[remote \"upstream\"]\n\turl = \"https://github.com/upstream/repo.git\" # mirror\n";
+    let origin = GitUrl::parse_from_git_config(config, "origin").expect("remote lookup failed");
+    let upstream = GitUrl::parse_from_git_config(config, "upstream").expect("remote lookup failed");
+
+    assert_eq!(origin.to_string(), "git@github.com:owner/repo.git");
+    assert_eq!(upstream.to_string(), "https://github.com/upstream/repo.git");
+}
+
+#[test]
+fn comment_characters_within_quotes() {
+    let config = "[remote \"a;b\"]\n\turl = \"https://github.com/owner/repo.git#main\"\n";
+    let parsed = GitUrl::parse_from_git_config(config, "a;b").expect("remote lookup failed");
+
+    assert_eq!(parsed.fullname, "owner/repo");
+    assert_eq!(parsed.fragment, Some("main".to_string()));
+}
@@ -0,0 +1,256 @@
+use parse_git_url::*;
+
+#[test]
+fn host_labels() {
+    let test_url = "https://a.b.github.com/owner/repo.git";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+    assert_eq!(parsed.host_labels(), vec!["a", "b", "github", "com"]);
+}
+
+#[test]
+fn host_labels_ipv4() {
+    let test_url = "https://192.168.1.1/owner/repo.git";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+    assert!(parsed.host_labels().is_empty());
+}
+
+#[test]
+fn host_labels_ipv6() {
+    let test_url = "https://[2001:db8::1]/owner/repo.git";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+    assert!(parsed.host_labels().is_empty());
+}
+
+#[test]
+fn host_labels_file() {
+    let test_url = "/path/to/repo.git";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+    assert!(parsed.host_labels().is_empty());
+}
+
+#[test]
+fn scheme_default_for_public_hosts() {
+    for host in ["github.com", "gitlab.com", "bitbucket.org", "GitHub.com"] {
+        assert_eq!(GitUrl::scheme_default_for_host(host), Scheme::Https);
+    }
+}
+
+#[test]
+fn scheme_default_for_self_hosted() {
+    assert_eq!(
+        GitUrl::scheme_default_for_host("git.example.com"),
+        Scheme::Ssh
+    );
+}
+
+#[test]
+fn is_wildcard_host() {
+    let test_url = "https://*.github.com/owner/repo.git";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+    assert_eq!(parsed.host, Some("*.github.com".to_string()));
+    assert_eq!(parsed.fullname, "owner/repo");
+    assert!(parsed.is_wildcard_host());
+}
+
+#[test]
+fn is_wildcard_host_scp() {
+    let test_url = "git@*.github.com:owner/repo.git";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+    assert!(parsed.is_wildcard_host());
+}
+
+#[test]
+fn is_wildcard_host_literal() {
+    let test_url = "https://github.com/owner/repo.git";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+    assert!(!parsed.is_wildcard_host());
+}
+
+#[test]
+fn reencode_idna_ssh() {
+    let test_url = "git@bücher.de:owner/repo.git";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+    let reencoded = parsed.reencode_idna().expect("IDNA reencoding failed");
+
+    assert_eq!(reencoded.host, Some("xn--bcher-kva.de".to_string()));
+    assert_eq!(reencoded.to_string(), "git@xn--bcher-kva.de:owner/repo.git");
+}
+
+#[test]
+fn reencode_idna_https() {
+    let test_url = "https://bücher.de/owner/repo.git";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+    let reencoded = parsed.reencode_idna().expect("IDNA reencoding failed");
+
+    assert_eq!(reencoded.host, Some("xn--bcher-kva.de".to_string()));
+}
+
+#[test]
+fn reencode_idna_ascii() {
+    let test_url = "https://github.com/owner/repo.git";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+    assert_eq!(
+        parsed.reencode_idna().expect("IDNA reencoding failed"),
+        parsed
+    );
+}
+
+#[test]
+fn reencode_idna_ipv6() {
+    let test_url = "ssh://git@[::1]:2222/owner/repo.git";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+    assert_eq!(
+        parsed.reencode_idna().expect("IDNA reencoding failed"),
+        parsed
+    );
+}
+
+#[test]
+fn is_ip_host_ipv4() {
+    let test_url = "https://192.168.1.10/owner/repo.git";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+    assert!(parsed.is_ip_host());
+}
+
+#[test]
+fn is_ip_host_ipv6() {
+    let test_url = "ssh://git@[2001:db8::1]:2222/owner/repo.git";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+    assert_eq!(parsed.host, Some("[2001:db8::1]".to_string()));
+    assert!(parsed.is_ip_host());
+}
+
+#[test]
+fn is_ip_host_dns_name() {
+    let test_url = "https://github.com/owner/repo.git";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+    assert!(!parsed.is_ip_host());
+}
+
+#[test]
+fn host_eq_default_port() {
+    let parsed = GitUrl::parse("https://github.com/owner/repo.git").expect("URL parse failed");
+    let with_port =
+        GitUrl::parse("https://github.com:443/owner/repo.git").expect("URL parse failed");
+
+    assert_eq!(with_port.port, Some(443));
+    assert!(parsed.host_eq(&with_port));
+    assert!(with_port.host_eq(&parsed));
+}
+
+#[test]
+fn host_eq_ignores_case() {
+    let parsed = GitUrl::parse("git@GitHub.com:owner/repo.git").expect("URL parse failed");
+    let other = GitUrl::parse("git@github.com:owner/repo.git").expect("URL parse failed");
+
+    assert!(parsed.host_eq(&other));
+}
+
+#[test]
+fn host_eq_custom_port() {
+    let parsed = GitUrl::parse("https://github.com/owner/repo.git").expect("URL parse failed");
+    let with_port =
+        GitUrl::parse("https://github.com:8443/owner/repo.git").expect("URL parse failed");
+
+    assert!(!parsed.host_eq(&with_port));
+}
+
+#[test]
+fn host_eq_other_host() {
+    let parsed = GitUrl::parse("https://github.com/owner/repo.git").expect("URL parse failed");
+    let other = GitUrl::parse("https://gitlab.com/owner/repo.git").expect("URL parse failed");
+
+    assert!(!parsed.host_eq(&other));
+}
+
+#[test]
+fn host_original_case_scp() {
+    let test_url = "git@GitHub.COM:owner/repo.git";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+    assert_eq!(parsed.host, Some("github.com".to_string()));
+    assert_eq!(parsed.host_original_case(), Some("GitHub.COM"));
+}
+
+#[test]
+fn host_original_case_https() {
+    let test_url = "https://GitHub.com@GitHub.com/owner/repo.git";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+    assert_eq!(parsed.host, Some("github.com".to_string()));
+    assert_eq!(parsed.host_original_case(), Some("GitHub.com"));
+}
+
+#[test]
+fn host_original_case_lowercase() {
+    let test_url = "https://github.com/owner/repo.git";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+    assert_eq!(parsed.original_host, None);
+    assert_eq!(parsed.host_original_case(), Some("github.com"));
+}
+
+#[test]
+fn host_is_known_public() {
+    for host in [
+        "github.com",
+        "gitlab.com",
+        "bitbucket.org",
+        "dev.azure.com",
+        "codeberg.org",
+        "git.sr.ht",
+    ] {
+        let git_url = GitUrl {
+            host: Some(host.to_string()),
+            ..Default::default()
+        };
+
+        assert!(git_url.host_is_known_public(), "{} is not public", host);
+    }
+}
+
+#[test]
+fn host_is_known_public_self_hosted() {
+    let test_url = "git@git.example.com:owner/repo.git";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+    assert!(!parsed.host_is_known_public());
+}
+
+#[test]
+fn canonical_host_trailing_dot() {
+    let test_url = "https://github.com./owner/repo.git";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+    assert_eq!(parsed.host, Some("github.com.".to_string()));
+    assert_eq!(parsed.canonical_host(), Some("github.com"));
+    assert_eq!(parsed.to_string(), test_url);
+    assert!(parsed.host_is_known_public());
+}
+
+#[test]
+fn canonical_host_without_trailing_dot() {
+    let parsed = GitUrl::parse("git@github.com:owner/repo.git").expect("URL parse failed");
+
+    assert_eq!(parsed.canonical_host(), Some("github.com"));
+}
+
+#[test]
+fn host_eq_trailing_dot() {
+    let parsed = GitUrl::parse("https://github.com./owner/repo.git").expect("URL parse failed");
+    let other = GitUrl::parse("git@github.com:owner/repo.git").expect("URL parse failed");
+
+    assert!(parsed.host_eq(&other));
+}
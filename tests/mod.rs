@@ -1,3 +1,17 @@
+mod azure;
+mod builder;
+mod compare;
+mod config;
+mod convert;
+mod display;
+mod host;
 mod normalize;
+mod options;
 mod parse;
+mod provider;
+mod query;
+mod route;
+mod scheme;
+mod serialize;
 mod trim_auth;
+mod validate;
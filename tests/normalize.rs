@@ -112,8 +112,7 @@ fn win_file_no_scheme_abs_path() {
     let test_url = "c:\\user\\project-name.git";
     let normalized = normalize_url(test_url).expect("Normalizing url failed");
 
-    // I actually don't know how this should be normalized.
-    assert_eq!(normalized.as_str(), "ssh://c/\\user\\project-name.git");
+    assert_eq!(normalized.as_str(), "file:///c:/user/project-name.git");
 }
 
 #[test]
@@ -161,3 +160,172 @@ fn null_in_input2() {
 
     assert!(normalized.is_err());
 }
+
+#[test]
+fn git_suffix_one_trailing_slash() {
+    let test_url = "https://host.tld/user/project-name.git/";
+    let normalized = normalize_url(test_url).expect("Normalizing url failed");
+
+    assert_eq!(
+        normalized.as_str(),
+        "https://host.tld/user/project-name.git"
+    );
+}
+
+#[test]
+fn git_suffix_two_trailing_slashes() {
+    let test_url = "https://host.tld/user/project-name.git//";
+    let normalized = normalize_url(test_url).expect("Normalizing url failed");
+
+    assert_eq!(
+        normalized.as_str(),
+        "https://host.tld/user/project-name.git"
+    );
+}
+
+#[test]
+fn git_suffix_three_trailing_slashes() {
+    let test_url = "https://host.tld/user/project-name.git///";
+    let normalized = normalize_url(test_url).expect("Normalizing url failed");
+
+    assert_eq!(
+        normalized.as_str(),
+        "https://host.tld/user/project-name.git"
+    );
+}
+
+#[test]
+fn git_suffix_trailing_slashes_before_query() {
+    let test_url = "https://host.tld/user/project-name.git//?depth=1";
+    let normalized = normalize_url(test_url).expect("Normalizing url failed");
+
+    assert_eq!(
+        normalized.as_str(),
+        "https://host.tld/user/project-name.git?depth=1"
+    );
+}
+
+#[test]
+fn ssh_empty_user() {
+    let test_url = "@host.tld:user/project-name.git";
+    let e = normalize_url(test_url).expect_err("Normalizing url should fail");
+
+    assert!(matches!(
+        e.kind(),
+        NormalizeUrlErrorKind::EmptySshUser { .. }
+    ));
+}
+
+#[test]
+fn double_quoted() {
+    let test_url = "\"https://host.tld/user/project-name.git\"";
+    let normalized = normalize_url(test_url).expect("Normalizing url failed");
+
+    assert_eq!(
+        normalized.as_str(),
+        "https://host.tld/user/project-name.git"
+    );
+}
+
+#[test]
+fn single_quoted() {
+    let test_url = "'git@host.tld:user/project-name.git'";
+    let normalized = normalize_url(test_url).expect("Normalizing url failed");
+
+    assert_eq!(
+        normalized.as_str(),
+        "ssh://git@host.tld/user/project-name.git"
+    );
+}
+
+#[test]
+fn mismatched_quotes_kept() {
+    let test_url = "\"https://host.tld/user/project-name.git'";
+    let normalized = normalize_url(test_url);
+
+    assert!(normalized.is_err());
+}
+
+#[test]
+fn win_file_no_scheme_forward_slashes() {
+    let test_url = "C:/user/project-name.git";
+    let normalized = normalize_url(test_url).expect("Normalizing url failed");
+
+    assert_eq!(normalized.as_str(), "file:///C:/user/project-name.git");
+}
+
+#[test]
+fn ssh_no_scheme_no_user_ip() {
+    let test_url = "192.168.1.1:user/project-name.git";
+    let normalized = normalize_url(test_url).expect("Normalizing url failed");
+
+    assert_eq!(
+        normalized.as_str(),
+        "ssh://192.168.1.1/user/project-name.git"
+    );
+}
+
+#[test]
+fn ssh_no_scheme_no_user_underscore_host() {
+    let test_url = "git_host.example.com:user/project-name.git";
+    let normalized = normalize_url(test_url).expect("Normalizing url failed");
+
+    assert_eq!(
+        normalized.as_str(),
+        "ssh://git_host.example.com/user/project-name.git"
+    );
+}
+
+#[test]
+fn ssh_scheme_ipv6() {
+    let test_url = "ssh://git@[2001:db8::1]/owner/repo.git";
+    let normalized = normalize_url(test_url).expect("Normalizing url failed");
+
+    assert_eq!(
+        normalized.as_str(),
+        "ssh://git@[2001:db8::1]/owner/repo.git"
+    );
+}
+
+#[test]
+fn ssh_scheme_ipv6_port() {
+    let test_url = "ssh://git@[2001:db8::1]:22/owner/repo.git";
+    let normalized = normalize_url(test_url).expect("Normalizing url failed");
+
+    assert_eq!(
+        normalized.as_str(),
+        "ssh://git@[2001:db8::1]:22/owner/repo.git"
+    );
+}
+
+#[test]
+fn ssh_no_scheme_ipv6() {
+    let test_url = "git@[::1]:owner/repo.git";
+    let normalized = normalize_url(test_url).expect("Normalizing url failed");
+
+    assert_eq!(normalized.as_str(), "ssh://git@[::1]/owner/repo.git");
+}
+
+#[test]
+fn ssh_no_scheme_ipv6_port() {
+    let test_url = "git@[::1]:2222:owner/repo.git";
+    let normalized = normalize_url(test_url).expect("Normalizing url failed");
+
+    assert_eq!(normalized.as_str(), "ssh://git@[::1]:2222/owner/repo.git");
+}
+
+#[test]
+fn uppercase_scheme() {
+    let test_url = "HTTPS://github.com/owner/repo";
+    let normalized = normalize_url(test_url).expect("Normalizing url failed");
+
+    assert_eq!(normalized.as_str(), "https://github.com/owner/repo");
+}
+
+#[test]
+fn mixed_case_git_short_form() {
+    let test_url = "Git:host.tld/owner/repo.git";
+    let normalized = normalize_url(test_url).expect("Normalizing url failed");
+
+    assert_eq!(normalized.as_str(), "git://host.tld/owner/repo.git");
+}
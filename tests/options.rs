@@ -0,0 +1,286 @@
+use parse_git_url::*;
+
+#[test]
+fn worktree_hint() {
+    let test_url = "/path/to/repo.git -- feature-branch";
+    let mut options = ParseOptions::default();
+    options.worktree_hints = true;
+    let parsed = GitUrl::parse_with_options(test_url, &options).expect("URL parse failed");
+
+    assert_eq!(parsed.scheme, Scheme::File);
+    assert_eq!(parsed.name, "repo");
+    assert_eq!(parsed.path, "/path/to/repo.git");
+    assert_eq!(parsed.worktree, Some("feature-branch".to_string()));
+}
+
+#[test]
+fn worktree_hint_absent() {
+    let test_url = "/path/to/repo.git";
+    let mut options = ParseOptions::default();
+    options.worktree_hints = true;
+    let parsed = GitUrl::parse_with_options(test_url, &options).expect("URL parse failed");
+
+    assert_eq!(parsed.name, "repo");
+    assert_eq!(parsed.path, "/path/to/repo.git");
+    assert_eq!(parsed.worktree, None);
+}
+
+#[test]
+fn worktree_hint_disabled() {
+    let test_url = "/path/to/repo.git -- feature-branch";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+    assert_eq!(parsed.worktree, None);
+}
+
+#[test]
+fn drop_default_ports() {
+    let test_url = "https://host.tld:443/owner/repo.git";
+    let mut options = ParseOptions::default();
+    options.drop_default_ports = true;
+    let parsed = GitUrl::parse_with_options(test_url, &options).expect("URL parse failed");
+
+    assert_eq!(parsed.port, None);
+    assert_eq!(format!("{}", parsed), "https://host.tld/owner/repo.git");
+}
+
+#[test]
+fn drop_default_ports_disabled() {
+    let test_url = "https://host.tld:443/owner/repo.git";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+    assert_eq!(parsed.port, Some(443));
+    assert_eq!(format!("{}", parsed), test_url);
+}
+
+#[test]
+fn drop_default_ports_keeps_custom_port() {
+    let test_url = "https://host.tld:8443/owner/repo.git";
+    let mut options = ParseOptions::default();
+    options.drop_default_ports = true;
+    let parsed = GitUrl::parse_with_options(test_url, &options).expect("URL parse failed");
+
+    assert_eq!(parsed.port, Some(8443));
+}
+
+#[test]
+fn drop_default_ports_ssh() {
+    let test_url = "ssh://git@host.tld:22/owner/repo.git";
+    let mut options = ParseOptions::default();
+    options.drop_default_ports = true;
+    let parsed = GitUrl::parse_with_options(test_url, &options).expect("URL parse failed");
+
+    assert_eq!(parsed.port, None);
+}
+
+#[test]
+fn allow_owner_only() {
+    let test_url = "https://github.com/owner/";
+    let mut options = ParseOptions::default();
+    options.allow_owner_only = true;
+    let parsed = GitUrl::parse_with_options(test_url, &options).expect("URL parse failed");
+
+    assert_eq!(parsed.owner, Some("owner".to_string()));
+    assert_eq!(parsed.name, "");
+    assert_eq!(parsed.fullname, "owner");
+    assert_eq!(parsed.path, "/owner");
+}
+
+#[test]
+fn allow_owner_only_without_trailing_slash() {
+    let test_url = "https://github.com/owner";
+    let mut options = ParseOptions::default();
+    options.allow_owner_only = true;
+    let parsed = GitUrl::parse_with_options(test_url, &options).expect("URL parse failed");
+
+    assert_eq!(parsed.owner, Some("owner".to_string()));
+    assert_eq!(parsed.name, "");
+}
+
+#[test]
+fn allow_owner_only_disabled() {
+    let test_url = "https://github.com/owner/";
+    let e = GitUrl::parse(test_url);
+
+    assert!(e.is_err());
+}
+
+#[test]
+fn allow_owner_only_keeps_repos() {
+    let test_url = "https://github.com/owner/repo.git";
+    let mut options = ParseOptions::default();
+    options.allow_owner_only = true;
+    let parsed = GitUrl::parse_with_options(test_url, &options).expect("URL parse failed");
+
+    assert_eq!(parsed.owner, Some("owner".to_string()));
+    assert_eq!(parsed.name, "repo");
+}
+
+#[test]
+fn flat_namespace_hosts_scp() {
+    let test_url = "git@gist.github.com:abc123def.git";
+    let mut options = ParseOptions::default();
+    options.flat_namespace_hosts = vec!["gist.github.com".to_string()];
+    let parsed = GitUrl::parse_with_options(test_url, &options).expect("URL parse failed");
+
+    assert_eq!(parsed.owner, None);
+    assert_eq!(parsed.name, "abc123def");
+    assert_eq!(parsed.fullname, "abc123def");
+    assert_eq!(format!("{}", parsed), test_url);
+}
+
+#[test]
+fn flat_namespace_hosts_ssh() {
+    let test_url = "ssh://git@flat.example.com/abc123def";
+    let mut options = ParseOptions::default();
+    options.flat_namespace_hosts = vec!["flat.example.com".to_string()];
+    let parsed = GitUrl::parse_with_options(test_url, &options).expect("URL parse failed");
+
+    assert_eq!(parsed.owner, None);
+    assert_eq!(parsed.name, "abc123def");
+}
+
+#[test]
+fn flat_namespace_hosts_other_host() {
+    let test_url = "ssh://git@other.example.com/abc123def";
+    let mut options = ParseOptions::default();
+    options.flat_namespace_hosts = vec!["flat.example.com".to_string()];
+    let parsed = GitUrl::parse_with_options(test_url, &options).expect("URL parse failed");
+
+    assert_eq!(parsed.owner, Some("abc123def".to_string()));
+}
+
+#[test]
+fn trailing_comment() {
+    let test_url = "git@host.tld:owner/repo.git # primary mirror";
+    let mut options = ParseOptions::default();
+    options.trailing_comments = true;
+    let parsed = GitUrl::parse_with_options(test_url, &options).expect("URL parse failed");
+
+    assert_eq!(parsed.fullname, "owner/repo");
+    assert_eq!(parsed.comment, Some("primary mirror".to_string()));
+    assert_eq!(parsed.to_string(), "git@host.tld:owner/repo.git");
+}
+
+#[test]
+fn trailing_comment_keeps_fragment() {
+    let test_url = "https://host.tld/owner/repo.git#main";
+    let mut options = ParseOptions::default();
+    options.trailing_comments = true;
+    let parsed = GitUrl::parse_with_options(test_url, &options).expect("URL parse failed");
+
+    assert_eq!(parsed.fragment, Some("main".to_string()));
+    assert_eq!(parsed.comment, None);
+}
+
+#[test]
+fn trailing_comment_disabled() {
+    let test_url = "git@host.tld:owner/repo.git # primary mirror";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+    assert_eq!(parsed.comment, None);
+}
+
+#[test]
+fn detect_github_tokens() {
+    let test_url = "https://ghp_abc123@github.com/owner/repo.git";
+    let mut options = ParseOptions::default();
+    options.detect_github_tokens = true;
+    let parsed = GitUrl::parse_with_options(test_url, &options).expect("URL parse failed");
+
+    assert_eq!(parsed.user, None);
+    assert_eq!(parsed.token, Some("ghp_abc123".to_string()));
+    assert_eq!(parsed.to_string(), test_url);
+}
+
+#[test]
+fn detect_github_tokens_fine_grained() {
+    let test_url = "https://github_pat_abc123@github.com/owner/repo.git";
+    let mut options = ParseOptions::default();
+    options.detect_github_tokens = true;
+    let parsed = GitUrl::parse_with_options(test_url, &options).expect("URL parse failed");
+
+    assert_eq!(parsed.user, None);
+    assert_eq!(parsed.token, Some("github_pat_abc123".to_string()));
+}
+
+#[test]
+fn detect_github_tokens_keeps_user() {
+    let test_url = "https://user@github.com/owner/repo.git";
+    let mut options = ParseOptions::default();
+    options.detect_github_tokens = true;
+    let parsed = GitUrl::parse_with_options(test_url, &options).expect("URL parse failed");
+
+    assert_eq!(parsed.user, Some("user".to_string()));
+    assert_eq!(parsed.token, None);
+}
+
+#[test]
+fn detect_github_tokens_disabled() {
+    let test_url = "https://ghp_abc123@github.com/owner/repo.git";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+    assert_eq!(parsed.user, Some("ghp_abc123".to_string()));
+    assert_eq!(parsed.token, None);
+}
+
+#[test]
+fn file_owners() {
+    let test_url = "/srv/git/team/repo.git";
+    let mut options = ParseOptions::default();
+    options.file_owners = true;
+    let parsed = GitUrl::parse_with_options(test_url, &options).expect("URL parse failed");
+
+    assert_eq!(parsed.owner, Some("team".to_string()));
+    assert_eq!(parsed.name, "repo");
+    assert_eq!(parsed.fullname, "team/repo");
+    assert_eq!(parsed.path, test_url);
+    assert!(parsed.validate().is_ok());
+}
+
+#[test]
+fn file_owners_with_spaces() {
+    let test_url = "/srv/git/my team/my repo.git";
+    let mut options = ParseOptions::default();
+    options.file_owners = true;
+    let parsed = GitUrl::parse_with_options(test_url, &options).expect("URL parse failed");
+
+    assert_eq!(parsed.owner, Some("my team".to_string()));
+    assert_eq!(parsed.fullname, "my team/my repo");
+}
+
+#[test]
+fn file_owners_windows_path() {
+    let mut options = ParseOptions::default();
+    options.file_owners = true;
+
+    let parsed =
+        GitUrl::parse_with_options("C:\\Users\\me\\repo.git", &options).expect("URL parse failed");
+    assert_eq!(parsed.owner, Some("me".to_string()));
+    assert_eq!(parsed.fullname, "me/repo");
+
+    // The drive is not a directory
+    let parsed = GitUrl::parse_with_options("C:\\repo.git", &options).expect("URL parse failed");
+    assert_eq!(parsed.owner, None);
+    assert_eq!(parsed.fullname, "repo");
+}
+
+#[test]
+fn file_owners_root() {
+    let mut options = ParseOptions::default();
+    options.file_owners = true;
+    let parsed = GitUrl::parse_with_options("/repo.git", &options).expect("URL parse failed");
+
+    assert_eq!(parsed.owner, None);
+    assert_eq!(parsed.fullname, "repo");
+}
+
+#[test]
+fn file_owners_ignores_network_urls() {
+    let test_url = "https://github.com/owner/repo.git";
+    let mut options = ParseOptions::default();
+    options.file_owners = true;
+    let parsed = GitUrl::parse_with_options(test_url, &options).expect("URL parse failed");
+
+    assert_eq!(parsed, GitUrl::parse(test_url).expect("URL parse failed"));
+}
@@ -1,4 +1,5 @@
 use parse_git_url::*;
+use std::error::Error;
 
 #[test]
 fn ssh_user_ports() {
@@ -17,6 +18,16 @@ fn ssh_user_ports() {
         path: "user/project-name.git".to_string(),
         git_suffix: true,
         scheme_prefix: true,
+        query: None,
+        fragment: None,
+        reference: None,
+        git_short_form: false,
+        git_plus_prefix: false,
+        git_prefix: false,
+        original_host: None,
+        groups: Vec::new(),
+        worktree: None,
+        comment: None,
     };
 
     assert_eq!(parsed, expected);
@@ -40,6 +51,16 @@ fn https_user_bitbucket() {
         path: "/user/repo.git".to_string(),
         git_suffix: true,
         scheme_prefix: true,
+        query: None,
+        fragment: None,
+        reference: None,
+        git_short_form: false,
+        git_plus_prefix: false,
+        git_prefix: false,
+        original_host: None,
+        groups: Vec::new(),
+        worktree: None,
+        comment: None,
     };
 
     assert_eq!(parsed, expected);
@@ -62,6 +83,16 @@ fn ssh_user_bitbucket() {
         path: "user/repo.git".to_string(),
         git_suffix: true,
         scheme_prefix: false,
+        query: None,
+        fragment: None,
+        reference: None,
+        git_short_form: false,
+        git_plus_prefix: false,
+        git_prefix: false,
+        original_host: None,
+        groups: Vec::new(),
+        worktree: None,
+        comment: None,
     };
 
     assert_eq!(parsed, expected);
@@ -84,6 +115,16 @@ fn https_user_auth_bitbucket() {
         path: "/owner/name.git".to_string(),
         git_suffix: true,
         scheme_prefix: true,
+        query: None,
+        fragment: None,
+        reference: None,
+        git_short_form: false,
+        git_plus_prefix: false,
+        git_prefix: false,
+        original_host: None,
+        groups: Vec::new(),
+        worktree: None,
+        comment: None,
     };
 
     assert_eq!(parsed, expected);
@@ -106,6 +147,16 @@ fn https_user_github() {
         path: "/user/repo.git".to_string(),
         git_suffix: true,
         scheme_prefix: true,
+        query: None,
+        fragment: None,
+        reference: None,
+        git_short_form: false,
+        git_plus_prefix: false,
+        git_prefix: false,
+        original_host: None,
+        groups: Vec::new(),
+        worktree: None,
+        comment: None,
     };
 
     assert_eq!(parsed, expected);
@@ -128,6 +179,16 @@ fn ssh_user_github() {
         path: "user/repo.git".to_string(),
         git_suffix: true,
         scheme_prefix: false,
+        query: None,
+        fragment: None,
+        reference: None,
+        git_short_form: false,
+        git_plus_prefix: false,
+        git_prefix: false,
+        original_host: None,
+        groups: Vec::new(),
+        worktree: None,
+        comment: None,
     };
 
     assert_eq!(parsed, expected);
@@ -150,6 +211,16 @@ fn https_user_auth_github() {
         path: "/owner/name.git".to_string(),
         git_suffix: true,
         scheme_prefix: true,
+        query: None,
+        fragment: None,
+        reference: None,
+        git_short_form: false,
+        git_plus_prefix: false,
+        git_prefix: false,
+        original_host: None,
+        groups: Vec::new(),
+        worktree: None,
+        comment: None,
     };
 
     assert_eq!(parsed, expected);
@@ -172,6 +243,16 @@ fn ssh_user_azure_devops() {
         path: "v3/CompanyName/ProjectName/RepoName".to_string(),
         git_suffix: false,
         scheme_prefix: false,
+        query: None,
+        fragment: None,
+        reference: None,
+        git_short_form: false,
+        git_plus_prefix: false,
+        git_prefix: false,
+        original_host: None,
+        groups: Vec::new(),
+        worktree: None,
+        comment: None,
     };
 
     assert_eq!(parsed, expected);
@@ -194,6 +275,16 @@ fn https_user_azure_devops() {
         path: "/organization/project/_git/repo".to_string(),
         git_suffix: false,
         scheme_prefix: true,
+        query: None,
+        fragment: None,
+        reference: None,
+        git_short_form: false,
+        git_plus_prefix: false,
+        git_prefix: false,
+        original_host: None,
+        groups: Vec::new(),
+        worktree: None,
+        comment: None,
     };
 
     assert_eq!(parsed, expected);
@@ -216,6 +307,48 @@ fn ftp_user() {
         path: "/user/project-name.git".to_string(),
         git_suffix: true,
         scheme_prefix: true,
+        query: None,
+        fragment: None,
+        reference: None,
+        git_short_form: false,
+        git_plus_prefix: false,
+        git_prefix: false,
+        original_host: None,
+        groups: Vec::new(),
+        worktree: None,
+        comment: None,
+    };
+
+    assert_eq!(parsed, expected);
+}
+
+#[test]
+fn rsync() {
+    let test_url = "rsync://host.tld/user/project-name.git";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+    let expected = GitUrl {
+        host: Some("host.tld".to_string()),
+        name: "project-name".to_string(),
+        owner: Some("user".to_string()),
+        organization: None,
+        fullname: "user/project-name".to_string(),
+        scheme: Scheme::Rsync,
+        user: None,
+        token: None,
+        port: None,
+        path: "/user/project-name.git".to_string(),
+        git_suffix: true,
+        scheme_prefix: true,
+        query: None,
+        fragment: None,
+        reference: None,
+        git_short_form: false,
+        git_plus_prefix: false,
+        git_prefix: false,
+        original_host: None,
+        groups: Vec::new(),
+        worktree: None,
+        comment: None,
     };
 
     assert_eq!(parsed, expected);
@@ -238,6 +371,16 @@ fn ftps_user() {
         path: "/user/project-name.git".to_string(),
         git_suffix: true,
         scheme_prefix: true,
+        query: None,
+        fragment: None,
+        reference: None,
+        git_short_form: false,
+        git_plus_prefix: false,
+        git_prefix: false,
+        original_host: None,
+        groups: Vec::new(),
+        worktree: None,
+        comment: None,
     };
 
     assert_eq!(parsed, expected);
@@ -260,6 +403,16 @@ fn relative_unix_path() {
         path: "../project-name.git".to_string(),
         git_suffix: true,
         scheme_prefix: false,
+        query: None,
+        fragment: None,
+        reference: None,
+        git_short_form: false,
+        git_plus_prefix: false,
+        git_prefix: false,
+        original_host: None,
+        groups: Vec::new(),
+        worktree: None,
+        comment: None,
     };
 
     assert_eq!(parsed, expected);
@@ -282,6 +435,16 @@ fn absolute_unix_path() {
         path: "/path/to/project-name.git".to_string(),
         git_suffix: true,
         scheme_prefix: false,
+        query: None,
+        fragment: None,
+        reference: None,
+        git_short_form: false,
+        git_plus_prefix: false,
+        git_prefix: false,
+        original_host: None,
+        groups: Vec::new(),
+        worktree: None,
+        comment: None,
     };
 
     assert_eq!(parsed, expected);
@@ -305,6 +468,16 @@ fn relative_windows_path() {
         path: "../project-name.git".to_string(),
         git_suffix: true,
         scheme_prefix: false,
+        query: None,
+        fragment: None,
+        reference: None,
+        git_short_form: false,
+        git_plus_prefix: false,
+        git_prefix: false,
+        original_host: None,
+        groups: Vec::new(),
+        worktree: None,
+        comment: None,
     };
 
     assert_eq!(parsed, expected);
@@ -313,9 +486,10 @@ fn relative_windows_path() {
 #[test]
 fn absolute_windows_path() {
     let test_url = "c:\\project-name.git";
-    let parsed = GitUrl::parse(test_url);
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
 
-    assert!(parsed.is_err());
+    assert_eq!(parsed.scheme, Scheme::File);
+    assert_eq!(parsed.name, "project-name");
 }
 
 #[test]
@@ -343,6 +517,16 @@ fn ssh_without_organization() {
         path: "repo".to_string(),
         git_suffix: false,
         scheme_prefix: true,
+        query: None,
+        fragment: None,
+        reference: None,
+        git_short_form: false,
+        git_plus_prefix: false,
+        git_prefix: false,
+        original_host: None,
+        groups: Vec::new(),
+        worktree: None,
+        comment: None,
     };
 
     assert_eq!(parsed, expected);
@@ -373,7 +557,1237 @@ fn git() {
         path: "/owner/name.git".to_string(),
         git_suffix: true,
         scheme_prefix: true,
+        query: None,
+        fragment: None,
+        reference: None,
+        git_short_form: true,
+        git_plus_prefix: false,
+        git_prefix: false,
+        original_host: None,
+        groups: Vec::new(),
+        worktree: None,
+        comment: None,
+    };
+
+    assert_eq!(parsed, expected);
+}
+
+#[test]
+fn https_user_percent_encoded_dot_host() {
+    let test_url = "https://user@git%2Eexample.com/owner/repo.git";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+    let expected = GitUrl {
+        host: Some("git.example.com".to_string()),
+        name: "repo".to_string(),
+        owner: Some("owner".to_string()),
+        organization: None,
+        fullname: "owner/repo".to_string(),
+        scheme: Scheme::Https,
+        user: Some("user".to_string()),
+        token: None,
+        port: None,
+        path: "/owner/repo.git".to_string(),
+        git_suffix: true,
+        scheme_prefix: true,
+        query: None,
+        fragment: None,
+        reference: None,
+        git_short_form: false,
+        git_plus_prefix: false,
+        git_prefix: false,
+        original_host: None,
+        groups: Vec::new(),
+        worktree: None,
+        comment: None,
+    };
+
+    assert_eq!(parsed, expected);
+}
+
+#[test]
+fn https_user_percent_encoded_hyphen_host() {
+    let test_url = "https://user@git%2Dlab.example.com/owner/repo.git";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+    let expected = GitUrl {
+        host: Some("git-lab.example.com".to_string()),
+        name: "repo".to_string(),
+        owner: Some("owner".to_string()),
+        organization: None,
+        fullname: "owner/repo".to_string(),
+        scheme: Scheme::Https,
+        user: Some("user".to_string()),
+        token: None,
+        port: None,
+        path: "/owner/repo.git".to_string(),
+        git_suffix: true,
+        scheme_prefix: true,
+        query: None,
+        fragment: None,
+        reference: None,
+        git_short_form: false,
+        git_plus_prefix: false,
+        git_prefix: false,
+        original_host: None,
+        groups: Vec::new(),
+        worktree: None,
+        comment: None,
+    };
+
+    assert_eq!(parsed, expected);
+}
+
+#[test]
+fn https_git_suffix_trailing_slashes() {
+    for test_url in [
+        "https://host.tld/owner/repo.git/",
+        "https://host.tld/owner/repo.git//",
+        "https://host.tld/owner/repo.git///",
+    ] {
+        let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+        assert_eq!(parsed.name, "repo");
+        assert_eq!(parsed.path, "/owner/repo.git");
+        assert!(parsed.git_suffix);
+    }
+}
+
+#[test]
+fn parse_bytes() {
+    let test_url = b"git@github.com:owner/repo.git";
+    let parsed = GitUrl::parse_bytes(test_url).expect("URL parse failed");
+    let expected = GitUrl::parse("git@github.com:owner/repo.git").expect("URL parse failed");
+
+    assert_eq!(parsed, expected);
+}
+
+#[test]
+fn parse_bytes_invalid_utf8() {
+    let test_url = b"https://github.com/owner/\xff\xfe.git";
+    let e = GitUrl::parse_bytes(test_url).expect_err("URL parse should fail");
+
+    assert!(matches!(e.kind(), FromStrErrorKind::InvalidUtf8 { .. }));
+}
+
+#[test]
+fn git_daemon_home_relative() {
+    let test_url = "git://host.tld/~user/repo.git";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+    let expected = GitUrl {
+        host: Some("host.tld".to_string()),
+        name: "repo".to_string(),
+        owner: Some("~user".to_string()),
+        organization: None,
+        fullname: "~user/repo".to_string(),
+        scheme: Scheme::Git,
+        user: None,
+        token: None,
+        port: None,
+        path: "/~user/repo.git".to_string(),
+        git_suffix: true,
+        scheme_prefix: true,
+        query: None,
+        fragment: None,
+        reference: None,
+        git_short_form: false,
+        git_plus_prefix: false,
+        git_prefix: false,
+        original_host: None,
+        groups: Vec::new(),
+        worktree: None,
+        comment: None,
+    };
+
+    assert_eq!(parsed, expected);
+    assert_eq!(parsed.daemon_home_user(), Some("user"));
+    assert_eq!(format!("{}", parsed), test_url);
+}
+
+#[test]
+fn git_daemon_home_relative_nested() {
+    let test_url = "git://host.tld/~user/projects/repo.git";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+    assert_eq!(parsed.owner, Some("~user".to_string()));
+    assert_eq!(parsed.fullname, "~user/repo");
+    assert_eq!(parsed.daemon_home_user(), Some("user"));
+}
+
+#[test]
+fn git_daemon_not_home_relative() {
+    let test_url = "git://host.tld/owner/repo.git";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+    assert_eq!(parsed.daemon_home_user(), None);
+}
+
+#[test]
+fn git_short_form_round_trip() {
+    let test_url = "git:github.com/owner/name.git";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+    assert_eq!(format!("{}", parsed), test_url);
+}
+
+#[test]
+fn git_scheme_round_trip() {
+    let test_url = "git://github.com/owner/name.git";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+    assert!(!parsed.git_short_form);
+    assert_eq!(format!("{}", parsed), test_url);
+}
+
+#[test]
+fn git_plus_relative_file() {
+    let test_url = "git+file:relative/repo";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+    assert!(parsed.git_plus_prefix);
+    assert_eq!(parsed.path, "relative/repo");
+    assert_eq!(parsed.to_string(), test_url);
+    assert_eq!(
+        GitUrl::parse(&parsed.to_string()).expect("URL parse failed"),
+        parsed
+    );
+}
+
+#[test]
+fn git_plus_file_single_slash() {
+    let test_url = "git+file:/abs/path/repo";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+    assert_eq!(parsed.path, "/abs/path/repo");
+    assert_eq!(parsed.to_string(), test_url);
+    assert_eq!(
+        GitUrl::parse(&parsed.to_string()).expect("URL parse failed"),
+        parsed
+    );
+}
+
+#[test]
+fn git_plus_file() {
+    let test_url = "git+file:///abs/path/repo";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+    let expected = GitUrl {
+        host: None,
+        name: "repo".to_string(),
+        owner: None,
+        organization: None,
+        fullname: "repo".to_string(),
+        scheme: Scheme::File,
+        user: None,
+        token: None,
+        port: None,
+        path: "/abs/path/repo".to_string(),
+        git_suffix: false,
+        scheme_prefix: true,
+        query: None,
+        fragment: None,
+        reference: None,
+        git_short_form: false,
+        git_plus_prefix: true,
+        git_prefix: false,
+        original_host: None,
+        groups: Vec::new(),
+        worktree: None,
+        comment: None,
+    };
+
+    assert_eq!(parsed, expected);
+    assert_eq!(format!("{}", parsed), test_url);
+}
+
+#[test]
+fn https_empty_userinfo() {
+    let test_url = "https://@github.com/owner/repo.git";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+    let expected = GitUrl {
+        host: Some("github.com".to_string()),
+        name: "repo".to_string(),
+        owner: Some("owner".to_string()),
+        organization: None,
+        fullname: "owner/repo".to_string(),
+        scheme: Scheme::Https,
+        user: None,
+        token: None,
+        port: None,
+        path: "/owner/repo.git".to_string(),
+        git_suffix: true,
+        scheme_prefix: true,
+        query: None,
+        fragment: None,
+        reference: None,
+        git_short_form: false,
+        git_plus_prefix: false,
+        git_prefix: false,
+        original_host: None,
+        groups: Vec::new(),
+        worktree: None,
+        comment: None,
     };
 
     assert_eq!(parsed, expected);
+    assert_eq!(format!("{}", parsed), "https://github.com/owner/repo.git");
+}
+
+#[test]
+fn parse_iter() {
+    let test_urls = [
+        "git@github.com:owner/repo.git",
+        "https://github.com/owner/repo.git",
+        "https://github.com:owner/repo.git",
+    ];
+    let pulled = std::cell::Cell::new(0);
+    let mut parsed = GitUrl::parse_iter(test_urls.iter().copied().inspect(|_| {
+        pulled.set(pulled.get() + 1);
+    }));
+
+    assert_eq!(pulled.get(), 0);
+
+    let first = parsed.next().expect("iterator ended early");
+    assert_eq!(pulled.get(), 1);
+    assert_eq!(first.expect("URL parse failed").name, "repo");
+
+    let rest = parsed.collect::<Vec<_>>();
+    assert_eq!(pulled.get(), 3);
+    assert!(rest[0].is_ok());
+    assert!(rest[1].is_err());
+}
+
+#[test]
+fn ssh_empty_user() {
+    let test_url = "@github.com:owner/repo.git";
+    let e = GitUrl::parse(test_url).expect_err("URL parse should fail");
+
+    assert!(e
+        .source()
+        .expect("error has a source")
+        .to_string()
+        .contains("empty user"));
+}
+
+#[test]
+fn parse_relative_to_sibling() {
+    let base = GitUrl::parse("https://github.com/owner/repo.git").expect("URL parse failed");
+    let parsed = GitUrl::parse_relative_to(&base, "../sibling.git").expect("URL parse failed");
+
+    assert_eq!(parsed.host, Some("github.com".to_string()));
+    assert_eq!(parsed.owner, Some("owner".to_string()));
+    assert_eq!(parsed.name, "sibling");
+    assert_eq!(parsed.to_string(), "https://github.com/owner/sibling.git");
+}
+
+#[test]
+fn parse_relative_to_other_owner() {
+    let base = GitUrl::parse("git@github.com:owner/repo.git").expect("URL parse failed");
+    let parsed = GitUrl::parse_relative_to(&base, "../../other/lib.git").expect("URL parse failed");
+
+    assert_eq!(parsed.owner, Some("other".to_string()));
+    assert_eq!(parsed.name, "lib");
+    assert_eq!(parsed.to_string(), "git@github.com:other/lib.git");
+}
+
+#[test]
+fn parse_relative_to_ssh_scheme_prefix() {
+    let base = GitUrl::parse("ssh://git@host.tld/owner/repo.git").expect("URL parse failed");
+    let parsed = GitUrl::parse_relative_to(&base, "../sibling.git").expect("URL parse failed");
+
+    assert_eq!(parsed.user, Some("git".to_string()));
+    assert_eq!(parsed.fullname, "owner/sibling");
+    assert!(parsed.scheme_prefix);
+}
+
+#[test]
+fn parse_relative_to_past_root() {
+    let base = GitUrl::parse("https://github.com/owner/repo.git").expect("URL parse failed");
+    let e = GitUrl::parse_relative_to(&base, "../../../sibling.git")
+        .expect_err("URL parse should fail");
+
+    assert!(matches!(e.kind(), FromStrErrorKind::MalformedGitUrl { .. }));
+}
+
+#[test]
+fn owner_segments_subgroup() {
+    let git_url = GitUrl {
+        host: Some("gitlab.com".to_string()),
+        name: "repo".to_string(),
+        owner: Some("group/subgroup".to_string()),
+        fullname: "group/subgroup/repo".to_string(),
+        scheme: Scheme::Https,
+        path: "/group/subgroup/repo.git".to_string(),
+        ..Default::default()
+    };
+
+    assert_eq!(git_url.owner_segments(), vec!["group", "subgroup"]);
+}
+
+#[test]
+fn owner_segments_flat() {
+    let parsed = GitUrl::parse("https://github.com/owner/repo.git").expect("URL parse failed");
+
+    assert_eq!(parsed.owner_segments(), vec!["owner"]);
+}
+
+#[test]
+fn owner_name_slug_flat() {
+    let parsed = GitUrl::parse("https://github.com/owner/repo.git").expect("URL parse failed");
+
+    assert_eq!(parsed.owner_name_slug("-"), "owner-repo");
+    assert_eq!(parsed.owner_name_slug("__"), "owner__repo");
+}
+
+#[test]
+fn owner_name_slug_subgroup() {
+    let parsed =
+        GitUrl::parse("https://gitlab.com/group/subgroup/repo.git").expect("URL parse failed");
+
+    assert_eq!(parsed.owner_name_slug("__"), "group__subgroup__repo");
+}
+
+#[test]
+fn owner_name_slug_encoded_slash() {
+    let parsed =
+        GitUrl::parse("https://git.example.com/owner%2Fteam/repo.git").expect("URL parse failed");
+
+    assert_eq!(parsed.owner_name_slug("-"), "owner-team-repo");
+}
+
+#[test]
+fn owner_name_slug_organization() {
+    let parsed =
+        GitUrl::parse("https://dev.azure.com/orgA/proj/_git/repo").expect("URL parse failed");
+    let other =
+        GitUrl::parse("https://dev.azure.com/orgB/proj/_git/repo").expect("URL parse failed");
+
+    assert_eq!(parsed.owner_name_slug("-"), "orgA-proj-repo");
+    assert_ne!(parsed.owner_name_slug("-"), other.owner_name_slug("-"));
+}
+
+#[test]
+fn owner_segments_no_owner() {
+    let parsed = GitUrl::parse("/path/to/repo.git").expect("URL parse failed");
+
+    assert!(parsed.owner_segments().is_empty());
+}
+
+#[test]
+fn git_prefix() {
+    let test_url = "git::https://github.com/owner/repo.git";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+    assert!(parsed.git_prefix);
+    assert_eq!(parsed.scheme, Scheme::Https);
+    assert_eq!(parsed.fullname, "owner/repo");
+    assert_eq!(parsed.to_string(), test_url);
+}
+
+#[test]
+fn with_git_prefix_add() {
+    let parsed = GitUrl::parse("https://github.com/owner/repo.git").expect("URL parse failed");
+
+    assert_eq!(
+        parsed.with_git_prefix(true).to_string(),
+        "git::https://github.com/owner/repo.git"
+    );
+}
+
+#[test]
+fn with_git_prefix_remove() {
+    let parsed = GitUrl::parse("git::git@github.com:owner/repo.git").expect("URL parse failed");
+    let stripped = parsed.with_git_prefix(false);
+
+    assert!(!stripped.git_prefix);
+    assert_eq!(stripped.to_string(), "git@github.com:owner/repo.git");
+}
+
+#[test]
+fn parse_with_base_host_https() {
+    let parsed = GitUrl::parse_with_base_host("/owner/repo.git", "github.com", Scheme::Https)
+        .expect("URL parse failed");
+
+    assert_eq!(parsed.host, Some("github.com".to_string()));
+    assert_eq!(parsed.scheme, Scheme::Https);
+    assert_eq!(parsed.fullname, "owner/repo");
+    assert_eq!(parsed.to_string(), "https://github.com/owner/repo.git");
+}
+
+#[test]
+fn parse_with_base_host_relative_path() {
+    let parsed = GitUrl::parse_with_base_host("owner/repo", "host.tld", Scheme::Ssh)
+        .expect("URL parse failed");
+
+    assert_eq!(parsed.host, Some("host.tld".to_string()));
+    assert_eq!(parsed.scheme, Scheme::Ssh);
+    assert_eq!(parsed.fullname, "owner/repo");
+}
+
+#[test]
+fn scp_numeric_path_segment_not_port() {
+    let test_url = "git@host.tld:1234/owner/repo.git";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+    assert_eq!(parsed.port, None);
+    assert_eq!(parsed.path, "1234/owner/repo.git");
+    assert_eq!(parsed.owner, Some("owner".to_string()));
+    assert_eq!(parsed.name, "repo");
+    assert_eq!(parsed.to_string(), test_url);
+}
+
+#[test]
+fn scp_numeric_owner_not_port() {
+    let test_url = "git@host.tld:1234/repo.git";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+    assert_eq!(parsed.port, None);
+    assert_eq!(parsed.owner, Some("1234".to_string()));
+    assert_eq!(parsed.path, "1234/repo.git");
+}
+
+#[test]
+fn map_owner_lowercase() {
+    let parsed = GitUrl::parse("https://github.com/Owner/repo.git").expect("URL parse failed");
+    let mapped = parsed.map_owner(|owner| owner.to_lowercase());
+
+    assert_eq!(mapped.owner, Some("owner".to_string()));
+    assert_eq!(mapped.fullname, "owner/repo");
+    assert_eq!(mapped.path, "/Owner/repo.git");
+}
+
+#[test]
+fn map_owner_without_owner() {
+    let parsed = GitUrl::parse("/path/to/repo.git").expect("URL parse failed");
+    let mapped = parsed.map_owner(|owner| owner.to_lowercase());
+
+    assert_eq!(mapped.owner, None);
+    assert_eq!(mapped.fullname, "repo");
+}
+
+#[test]
+fn map_name_azure() {
+    let parsed =
+        GitUrl::parse("https://dev.azure.com/org/project/_git/repo").expect("URL parse failed");
+    let mapped = parsed.map_name(|name| format!("{}-fork", name));
+
+    assert_eq!(mapped.name, "repo-fork");
+    assert_eq!(mapped.fullname, "org/project/repo-fork");
+}
+
+#[test]
+fn map_path() {
+    let parsed = GitUrl::parse("https://github.com/owner/repo").expect("URL parse failed");
+    let mapped = parsed.map_path(|path| format!("{}.git", path));
+
+    assert_eq!(mapped.to_string(), "https://github.com/owner/repo.git");
+}
+
+#[test]
+fn nested_quotes_strip_one_pair() {
+    let parsed =
+        GitUrl::parse("\"'https://github.com/owner/repo.git'\"").expect("URL parse failed");
+
+    // Only the outer quotes are removed, leaving a quoted string rather than an https url
+    assert_ne!(parsed.scheme, Scheme::Https);
+    assert_eq!(parsed.path, "'https://github.com/owner/repo.git'");
+
+    let parsed = GitUrl::parse("\"/path/'quoted'\"").expect("URL parse failed");
+    assert_eq!(parsed.name, "'quoted'");
+}
+
+#[test]
+fn quoted_git_short_form() {
+    let test_url = "'git:host.tld/user/project-name.git'";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+    assert!(parsed.git_short_form);
+    assert_eq!(parsed.fullname, "user/project-name");
+    assert_eq!(parsed.to_string(), "git:host.tld/user/project-name.git");
+}
+
+#[test]
+fn is_submodule_relative_parent() {
+    assert!(GitUrl::is_submodule_relative("../x.git"));
+}
+
+#[test]
+fn is_submodule_relative_current() {
+    assert!(GitUrl::is_submodule_relative("./x.git"));
+}
+
+#[test]
+fn is_submodule_relative_absolute() {
+    assert!(!GitUrl::is_submodule_relative(
+        "https://github.com/owner/x.git"
+    ));
+    assert!(!GitUrl::is_submodule_relative("/srv/git/x.git"));
+}
+
+#[test]
+fn resolve_submodule_sibling() {
+    let parent =
+        GitUrl::parse("https://gitlab.com/group/subgroup/parent.git").expect("URL parse failed");
+    let resolved =
+        GitUrl::resolve_submodule(&parent, "../sibling.git").expect("URL resolution failed");
+
+    assert_eq!(resolved.name, "sibling");
+    assert_eq!(resolved.path, "/group/subgroup/sibling.git");
+    assert_eq!(
+        resolved.to_string(),
+        "https://gitlab.com/group/subgroup/sibling.git"
+    );
+}
+
+#[test]
+fn resolve_submodule_other_owner() {
+    let parent =
+        GitUrl::parse("git@gitlab.com:group/subgroup/parent.git").expect("URL parse failed");
+    let resolved =
+        GitUrl::resolve_submodule(&parent, "../../other/repo.git").expect("URL resolution failed");
+
+    assert_eq!(resolved.owner, Some("other".to_string()));
+    assert_eq!(resolved.name, "repo");
+    assert_eq!(resolved.to_string(), "git@gitlab.com:group/other/repo.git");
+}
+
+#[test]
+fn resolve_submodule_scp_with_port() {
+    let parent = GitUrl::parse("git@host.tld:2222:owner/repo.git").expect("URL parse failed");
+    let resolved = GitUrl::resolve_submodule(&parent, "../sib.git").expect("URL resolution failed");
+
+    assert_eq!(resolved.port, Some(2222));
+    assert_eq!(resolved.path, "owner/sib.git");
+    assert_eq!(
+        resolved.to_string(),
+        "ssh://git@host.tld:2222/owner/sib.git"
+    );
+}
+
+#[test]
+fn resolve_submodule_absolute() {
+    let parent = GitUrl::parse("https://github.com/owner/parent.git").expect("URL parse failed");
+    let resolved = GitUrl::resolve_submodule(&parent, "git@host.tld:owner/repo.git")
+        .expect("URL resolution failed");
+
+    assert_eq!(resolved.host, Some("host.tld".to_string()));
+    assert_eq!(resolved.fullname, "owner/repo");
+}
+
+#[test]
+fn gitlab_subgroups() {
+    let test_url = "https://gitlab.com/group/subgroup/deeper/repo.git";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+    let expected = GitUrl {
+        host: Some("gitlab.com".to_string()),
+        name: "repo".to_string(),
+        owner: Some("deeper".to_string()),
+        organization: None,
+        fullname: "group/subgroup/deeper/repo".to_string(),
+        scheme: Scheme::Https,
+        user: None,
+        token: None,
+        port: None,
+        path: "/group/subgroup/deeper/repo.git".to_string(),
+        git_suffix: true,
+        scheme_prefix: true,
+        query: None,
+        fragment: None,
+        reference: None,
+        git_short_form: false,
+        git_plus_prefix: false,
+        git_prefix: false,
+        original_host: None,
+        groups: vec![
+            "group".to_string(),
+            "subgroup".to_string(),
+            "deeper".to_string(),
+        ],
+        worktree: None,
+        comment: None,
+    };
+
+    assert_eq!(parsed, expected);
+    assert_eq!(parsed.to_string(), test_url);
+    assert_eq!(parsed.owner_segments(), vec!["group", "subgroup", "deeper"]);
+}
+
+#[test]
+fn gitlab_subgroups_scp() {
+    let test_url = "git@gitlab.com:group/subgroup/repo.git";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+    assert_eq!(parsed.owner, Some("subgroup".to_string()));
+    assert_eq!(parsed.groups, vec!["group", "subgroup"]);
+    assert_eq!(parsed.fullname, "group/subgroup/repo");
+    assert_eq!(parsed.to_string(), test_url);
+}
+
+#[test]
+fn gitlab_single_group() {
+    let test_url = "https://gitlab.com/owner/repo.git";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+    assert_eq!(parsed.groups, vec!["owner"]);
+    assert_eq!(parsed.fullname, "owner/repo");
+}
+
+#[test]
+fn deep_path_without_subgroups() {
+    let test_url = "https://host.tld/group/subgroup/repo.git";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+    assert!(parsed.groups.is_empty());
+    assert_eq!(parsed.owner, Some("subgroup".to_string()));
+    assert_eq!(parsed.fullname, "subgroup/repo");
+}
+
+#[test]
+fn subgroup_hosts_self_hosted() {
+    let test_url = "https://gitlab.example.com/group/subgroup/repo.git";
+    let mut options = ParseOptions::default();
+    options.subgroup_hosts = vec!["gitlab.example.com".to_string()];
+    let parsed = GitUrl::parse_with_options(test_url, &options).expect("URL parse failed");
+
+    assert_eq!(parsed.groups, vec!["group", "subgroup"]);
+    assert_eq!(parsed.fullname, "group/subgroup/repo");
+}
+
+#[test]
+fn map_owner_subgroups() {
+    let parsed =
+        GitUrl::parse("https://gitlab.com/Group/SubGroup/repo.git").expect("URL parse failed");
+    let mapped = parsed.map_owner(|owner| owner.to_lowercase());
+
+    assert_eq!(mapped.groups, vec!["Group", "subgroup"]);
+    assert_eq!(mapped.fullname, "Group/subgroup/repo");
+}
+
+#[test]
+fn ssh_encoded_slash_in_name() {
+    let test_url = "ssh://git@host.tld/owner/my%2Frepo.git";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+    assert_eq!(parsed.owner, Some("owner".to_string()));
+    assert_eq!(parsed.name, "my/repo");
+    assert_eq!(parsed.fullname, "owner/my/repo");
+    assert_eq!(parsed.path, "owner/my%2Frepo.git");
+    assert_eq!(parsed.to_string(), test_url);
+    assert_eq!(
+        GitUrl::parse(&parsed.to_string()).expect("URL parse failed"),
+        parsed
+    );
+}
+
+#[test]
+fn https_encoded_space_in_name() {
+    let test_url = "https://host.tld/owner/my%20repo.git";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+    assert_eq!(parsed.name, "my repo");
+    assert_eq!(parsed.path, "/owner/my%20repo.git");
+    assert_eq!(parsed.to_string(), test_url);
+}
+
+#[test]
+fn scp_without_user() {
+    let test_url = "github.com:owner/repo.git";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+    assert_eq!(parsed.scheme, Scheme::Ssh);
+    assert_eq!(parsed.user, None);
+    assert_eq!(parsed.host, Some("github.com".to_string()));
+    assert_eq!(parsed.fullname, "owner/repo");
+    assert_eq!(parsed.to_string(), test_url);
+}
+
+#[test]
+fn scp_with_user() {
+    let test_url = "git@github.com:owner/repo.git";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+    assert_eq!(parsed.scheme, Scheme::Ssh);
+    assert_eq!(parsed.user, Some("git".to_string()));
+    assert_eq!(parsed.fullname, "owner/repo");
+}
+
+#[test]
+fn git_daemon_flat_without_suffix() {
+    let test_url = "git://host.tld/reponame";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+    let expected = GitUrl {
+        host: Some("host.tld".to_string()),
+        name: "reponame".to_string(),
+        owner: None,
+        organization: None,
+        fullname: "reponame".to_string(),
+        scheme: Scheme::Git,
+        user: None,
+        token: None,
+        port: None,
+        path: "/reponame".to_string(),
+        git_suffix: false,
+        scheme_prefix: true,
+        query: None,
+        fragment: None,
+        reference: None,
+        git_short_form: false,
+        git_plus_prefix: false,
+        git_prefix: false,
+        original_host: None,
+        groups: Vec::new(),
+        worktree: None,
+        comment: None,
+    };
+
+    assert_eq!(parsed, expected);
+    assert_eq!(parsed.to_string(), test_url);
+}
+
+#[test]
+fn git_daemon_flat_with_suffix() {
+    let test_url = "git://host.tld/reponame.git";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+    assert_eq!(parsed.owner, None);
+    assert_eq!(parsed.name, "reponame");
+    assert!(parsed.git_suffix);
+}
+
+#[test]
+fn with_path_github() {
+    let parsed = GitUrl::parse("https://github.com/owner/repo.git").expect("URL parse failed");
+    let rewritten = parsed
+        .with_path("/other/project.git")
+        .expect("URL parse failed");
+
+    assert_eq!(rewritten.owner, Some("other".to_string()));
+    assert_eq!(rewritten.name, "project");
+    assert_eq!(rewritten.fullname, "other/project");
+    assert_eq!(rewritten.path, "/other/project.git");
+    assert_eq!(
+        rewritten.to_string(),
+        "https://github.com/other/project.git"
+    );
+}
+
+#[test]
+fn with_path_ssh_keeps_shape() {
+    let parsed = GitUrl::parse("ssh://git@github.com/owner/repo.git").expect("URL parse failed");
+    let rewritten = parsed.with_path("other/project").expect("URL parse failed");
+
+    assert_eq!(rewritten.fullname, "other/project");
+    assert_eq!(rewritten.path, "other/project");
+    assert!(!rewritten.git_suffix);
+    assert!(rewritten.scheme_prefix);
+}
+
+#[test]
+fn with_path_scp_with_port() {
+    let parsed = GitUrl::parse("git@host.tld:2222:owner/repo.git").expect("URL parse failed");
+    let rewritten = parsed
+        .with_path("other/thing.git")
+        .expect("URL parse failed");
+
+    assert_eq!(rewritten.port, Some(2222));
+    assert_eq!(rewritten.path, "other/thing.git");
+    assert_eq!(rewritten.fullname, "other/thing");
+    assert_eq!(
+        rewritten.to_string(),
+        "ssh://git@host.tld:2222/other/thing.git"
+    );
+}
+
+#[test]
+fn with_path_malformed() {
+    let parsed = GitUrl::parse("https://github.com/owner/repo.git").expect("URL parse failed");
+    let e = parsed.with_path("/").expect_err("URL parse should fail");
+
+    assert!(matches!(e.kind(), FromStrErrorKind::MalformedGitUrl { .. }));
+}
+
+#[test]
+fn scp_git_user_has_no_scheme_prefix() {
+    let test_url = "git@github.com:owner/repo.git";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+    assert_eq!(parsed.scheme, Scheme::Ssh);
+    assert!(!parsed.scheme_prefix);
+    assert!(!parsed.git_short_form);
+    assert_eq!(parsed.to_string(), test_url);
+}
+
+#[test]
+fn ssh_git_user_keeps_scheme_prefix() {
+    let test_url = "ssh://git@github.com/owner/repo.git";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+    assert!(parsed.scheme_prefix);
+    assert_eq!(parsed.user, Some("git".to_string()));
+}
+
+#[test]
+fn git_scheme_and_short_form_have_scheme_prefix() {
+    let parsed = GitUrl::parse("git://github.com/owner/repo.git").expect("URL parse failed");
+    assert!(parsed.scheme_prefix);
+    assert!(!parsed.git_short_form);
+
+    let parsed = GitUrl::parse("git:github.com/owner/repo.git").expect("URL parse failed");
+    assert!(parsed.scheme_prefix);
+    assert!(parsed.git_short_form);
+}
+
+#[test]
+fn ssh_ipv6_host() {
+    let test_url = "ssh://git@[2001:db8::1]:22/owner/repo.git";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+    assert_eq!(parsed.host, Some("[2001:db8::1]".to_string()));
+    assert_eq!(parsed.port, Some(22));
+    assert_eq!(parsed.fullname, "owner/repo");
+    assert_eq!(parsed.to_string(), test_url);
+}
+
+#[test]
+fn ssh_ipv6_host_without_port() {
+    let test_url = "ssh://git@[2001:db8::1]/owner/repo.git";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+    assert_eq!(parsed.host, Some("[2001:db8::1]".to_string()));
+    assert_eq!(parsed.port, None);
+    assert_eq!(parsed.to_string(), test_url);
+    assert_eq!(
+        GitUrl::parse(&parsed.to_string()).expect("URL parse failed"),
+        parsed
+    );
+}
+
+#[test]
+fn scp_ipv6_host() {
+    let test_url = "git@[::1]:owner/repo.git";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+    assert_eq!(parsed.host, Some("[::1]".to_string()));
+    assert_eq!(parsed.port, None);
+    assert_eq!(parsed.user, Some("git".to_string()));
+    assert_eq!(parsed.fullname, "owner/repo");
+    assert_eq!(parsed.to_string(), test_url);
+}
+
+#[test]
+fn scp_ipv6_host_with_port() {
+    let test_url = "git@[::1]:2222:owner/repo.git";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+    assert_eq!(parsed.host, Some("[::1]".to_string()));
+    assert_eq!(parsed.port, Some(2222));
+    assert_eq!(parsed.fullname, "owner/repo");
+    assert_eq!(parsed.to_string(), "ssh://git@[::1]:2222/owner/repo.git");
+
+    let reparsed = GitUrl::parse(&parsed.to_string()).expect("URL parse failed");
+    assert_eq!(reparsed.host, parsed.host);
+    assert_eq!(reparsed.port, parsed.port);
+    assert_eq!(reparsed.path, parsed.path);
+}
+
+#[test]
+fn clone_command() {
+    let test_url = "git clone https://github.com/owner/repo.git";
+    let parsed = GitUrl::parse_clone_command(test_url).expect("URL parse failed");
+
+    assert_eq!(
+        parsed,
+        GitUrl::parse("https://github.com/owner/repo.git").expect("URL parse failed")
+    );
+}
+
+#[test]
+fn clone_command_with_options() {
+    let test_url = "git clone --depth 1 -b main --single-branch git@github.com:owner/repo.git dir";
+    let parsed = GitUrl::parse_clone_command(test_url).expect("URL parse failed");
+
+    assert_eq!(parsed.to_string(), "git@github.com:owner/repo.git");
+    assert_eq!(parsed.fullname, "owner/repo");
+}
+
+#[test]
+fn clone_command_with_filter() {
+    let test_url = "git clone --filter blob:none https://github.com/o/r.git";
+    let parsed = GitUrl::parse_clone_command(test_url).expect("URL parse failed");
+
+    assert_eq!(parsed.to_string(), "https://github.com/o/r.git");
+    assert_eq!(parsed.fullname, "o/r");
+}
+
+#[test]
+fn clone_command_without_command() {
+    let test_url = "  https://github.com/owner/repo.git ";
+    let parsed = GitUrl::parse_clone_command(test_url).expect("URL parse failed");
+
+    assert_eq!(parsed.to_string(), "https://github.com/owner/repo.git");
+}
+
+#[test]
+fn clone_command_with_global_options() {
+    let test_url = "git -C /tmp -c core.autocrlf=false --git-dir=/tmp/.git clone https://github.com/owner/repo.git";
+    let parsed = GitUrl::parse_clone_command(test_url).expect("URL parse failed");
+
+    assert_eq!(parsed.to_string(), "https://github.com/owner/repo.git");
+    assert_eq!(parsed.fullname, "owner/repo");
+}
+
+#[test]
+fn clone_command_other_subcommand() {
+    let test_url = "git -C /tmp fetch https://github.com/owner/repo.git";
+    let e = GitUrl::parse_clone_command(test_url).expect_err("URL parse should fail");
+
+    assert!(matches!(e.kind(), FromStrErrorKind::MalformedGitUrl { .. }));
+}
+
+#[test]
+fn clone_command_missing_url() {
+    let test_url = "git clone --depth 1";
+    let e = GitUrl::parse_clone_command(test_url).expect_err("URL parse should fail");
+
+    assert!(matches!(e.kind(), FromStrErrorKind::MalformedGitUrl { .. }));
+}
+
+#[test]
+fn mixed_case_schemes() {
+    let test_urls = [
+        ("HTTPS://github.com/owner/repo", Scheme::Https),
+        ("Git://github.com/owner/repo.git", Scheme::Git),
+        ("Git+SSH://git@github.com/owner/repo.git", Scheme::GitSsh),
+        ("GIT:github.com/owner/repo.git", Scheme::Git),
+    ];
+
+    for (test_url, scheme) in test_urls {
+        let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+        assert_eq!(parsed.scheme, scheme, "{}", test_url);
+        assert_eq!(parsed.fullname, "owner/repo", "{}", test_url);
+        assert!(parsed.scheme_prefix, "{}", test_url);
+    }
+}
+
+#[test]
+fn git_suffix_forms() {
+    let test_urls = [
+        ("https://github.com/owner/repo.git/", true),
+        ("https://github.com/owner/repo.git", true),
+        ("https://github.com/owner/repo", false),
+        ("https://github.com/owner/repo/", false),
+        ("git@github.com:owner/repo.git/", true),
+        ("https://github.com/owner/repo.git.git", true),
+        ("https://github.com/owner/repo/.git", true),
+    ];
+
+    for (test_url, git_suffix) in test_urls {
+        let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+        assert_eq!(parsed.name, "repo", "{}", test_url);
+        assert_eq!(parsed.fullname, "owner/repo", "{}", test_url);
+        assert_eq!(parsed.git_suffix, git_suffix, "{}", test_url);
+    }
+}
+
+#[test]
+fn trim_git_suffix_paths() {
+    assert_eq!(trim_git_suffix("owner/repo.git/"), "owner/repo");
+    assert_eq!(trim_git_suffix("owner/repo.git"), "owner/repo");
+    assert_eq!(trim_git_suffix("owner/repo"), "owner/repo");
+    assert_eq!(trim_git_suffix("owner/repo.git.git"), "owner/repo");
+    assert_eq!(trim_git_suffix("/path/to/repo/.git/"), "/path/to/repo");
+    assert_eq!(
+        trim_git_suffix("owner/repo.github.io"),
+        "owner/repo.github.io"
+    );
+}
+
+#[test]
+fn scp_empty_path() {
+    let test_urls = [
+        "git@host.tld:",
+        "AzureDiamond@host.tld:",
+        "git@host.tld:/",
+        "ssh://git@host.tld",
+    ];
+
+    for test_url in test_urls {
+        let e = GitUrl::parse(test_url).expect_err("URL parse should fail");
+
+        assert!(
+            matches!(e.kind(), FromStrErrorKind::MalformedGitUrl { .. }),
+            "{}",
+            test_url
+        );
+    }
+}
+
+#[test]
+fn scp_non_standard_user() {
+    let test_url = "AzureDiamond@host.tld:owner/repo.git";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+    assert_eq!(parsed.user, Some("AzureDiamond".to_string()));
+    assert_eq!(parsed.fullname, "owner/repo");
+    assert_eq!(parsed.to_string(), test_url);
+}
+
+#[test]
+fn parse_url_matches_parse() {
+    let test_urls = [
+        "https://github.com/owner/repo.git",
+        "ssh://git@host.tld:2222/owner/repo.git",
+        "git://host.tld/owner/repo",
+        "https://dev.azure.com/organization/project/_git/repo",
+        "https://gitlab.com/group/subgroup/repo.git?ref=main",
+    ];
+
+    for test_url in test_urls {
+        let u = url::Url::parse(test_url).expect("Url parse failed");
+        let parsed = GitUrl::parse_url(u).expect("URL parse failed");
+
+        assert_eq!(
+            parsed,
+            GitUrl::parse(test_url).expect("URL parse failed"),
+            "{}",
+            test_url
+        );
+    }
+}
+
+#[test]
+fn parse_url_trailing_slash() {
+    let u = url::Url::parse("https://github.com/owner/repo.git/").expect("Url parse failed");
+    let parsed = GitUrl::parse_url(u).expect("URL parse failed");
+
+    assert_eq!(parsed.path, "/owner/repo.git");
+    assert_eq!(parsed.name, "repo");
+}
+
+#[test]
+fn parse_url_unsupported_scheme() {
+    let u = url::Url::parse("gopher://host.tld/owner/repo").expect("Url parse failed");
+    let e = GitUrl::parse_url(u).expect_err("URL parse should fail");
+
+    assert!(matches!(
+        e.kind(),
+        FromStrErrorKind::UnsupportedScheme { .. }
+    ));
+}
+
+#[test]
+fn relative_file_url() {
+    let test_url = "file:relative/repo";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+    assert_eq!(parsed.scheme, Scheme::File);
+    assert_eq!(parsed.host, None);
+    assert_eq!(parsed.path, "relative/repo");
+    assert_eq!(parsed.name, "repo");
+    assert_eq!(parsed, GitUrl::parse("relative/repo").unwrap());
+}
+
+#[test]
+fn relative_file_url_dot_segments() {
+    let parsed = GitUrl::parse("file:../repo.git").expect("URL parse failed");
+
+    assert_eq!(parsed.scheme, Scheme::File);
+    assert_eq!(parsed.path, "../repo.git");
+    assert_eq!(parsed.name, "repo");
+}
+
+#[test]
+fn relative_file_url_mixed_case() {
+    let test_url = "file:Foo/Repo";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+    assert_eq!(parsed.host, None);
+    assert_eq!(parsed.path, "Foo/Repo");
+    assert_eq!(parsed.name, "Repo");
+    assert_eq!(parsed.to_string(), "Foo/Repo");
+}
+
+#[test]
+fn relative_file_url_single_segment() {
+    let parsed = GitUrl::parse("file:repo.git").expect("URL parse failed");
+
+    assert_eq!(parsed.scheme, Scheme::File);
+    assert_eq!(parsed.host, None);
+    assert_eq!(parsed.path, "repo.git");
+    assert_eq!(parsed.name, "repo");
+    assert!(parsed.git_suffix);
+}
+
+#[test]
+fn relative_file_url_with_spaces() {
+    let parsed = GitUrl::parse("file:my dir/repo").expect("URL parse failed");
+
+    assert_eq!(parsed.host, None);
+    assert_eq!(parsed.path, "my dir/repo");
+    assert_eq!(parsed.name, "repo");
+    assert_eq!(
+        GitUrl::parse(&parsed.to_string()).expect("URL parse failed"),
+        parsed
+    );
+}
+
+#[test]
+fn relative_path_not_ssh() {
+    let parsed = GitUrl::parse("relative/repo").expect("URL parse failed");
+    assert_eq!(parsed.scheme, Scheme::File);
+    assert_eq!(parsed.path, "relative/repo");
+
+    let parsed = GitUrl::parse("host.tld:owner/repo").expect("URL parse failed");
+    assert_eq!(parsed.scheme, Scheme::Ssh);
+    assert_eq!(parsed.host, Some("host.tld".to_string()));
+}
+
+#[test]
+fn file_path_with_spaces() {
+    let test_url = "/path/to/my repo.git";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+    // The path stays percent-encoded, while the name is decoded
+    assert_eq!(parsed.path, "/path/to/my%20repo.git");
+    assert_eq!(parsed.name, "my repo");
+    assert_eq!(parsed.owner, None);
+    assert_eq!(parsed.fullname, "my repo");
+}
+
+#[test]
+fn file_path_parent_with_spaces() {
+    let parsed = GitUrl::parse("/path/my owner/repo.git").expect("URL parse failed");
+
+    assert_eq!(parsed.path, "/path/my%20owner/repo.git");
+    assert_eq!(parsed.name, "repo");
+    assert_eq!(parsed.owner, None);
+}
+
+#[test]
+fn windows_path_backslashes() {
+    let parsed = GitUrl::parse("C:\\Users\\me\\repo.git").expect("URL parse failed");
+
+    assert_eq!(parsed.scheme, Scheme::File);
+    assert_eq!(parsed.path, "/C:/Users/me/repo.git");
+    assert_eq!(parsed.name, "repo");
+    assert_eq!(parsed.owner, None);
+    assert_eq!(parsed.fullname, "repo");
+}
+
+#[test]
+fn windows_path_backslashes_with_spaces() {
+    let parsed = GitUrl::parse("C:\\Users\\me\\my repo").expect("URL parse failed");
+
+    assert_eq!(parsed.path, "/C:/Users/me/my%20repo");
+    assert_eq!(parsed.name, "my repo");
+    assert!(!parsed.git_suffix);
+}
+
+#[test]
+fn relative_path_backslashes() {
+    let parsed = GitUrl::parse("dir\\sub\\repo.git").expect("URL parse failed");
+
+    assert_eq!(parsed.scheme, Scheme::File);
+    assert_eq!(parsed.path, "dir/sub/repo.git");
+    assert_eq!(parsed.name, "repo");
+    assert_eq!(parsed.owner, None);
 }
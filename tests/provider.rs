@@ -0,0 +1,156 @@
+use parse_git_url::*;
+
+#[test]
+fn provider_github() {
+    let parsed = GitUrl::parse("git@github.com:owner/repo.git").expect("URL parse failed");
+
+    assert_eq!(parsed.provider(), Provider::GitHub);
+}
+
+#[test]
+fn provider_github_default_port() {
+    let parsed = GitUrl::parse("https://github.com:443/owner/repo.git").expect("URL parse failed");
+    let other = GitUrl::parse("https://github.com/owner/repo.git").expect("URL parse failed");
+
+    assert_eq!(parsed.provider(), Provider::GitHub);
+    assert_eq!(parsed.provider(), other.provider());
+}
+
+#[test]
+fn provider_public_hosts() {
+    let test_urls = [
+        (
+            "https://gitlab.com/group/subgroup/repo.git",
+            Provider::GitLab,
+        ),
+        ("https://bitbucket.org/owner/repo.git", Provider::Bitbucket),
+        (
+            "https://dev.azure.com/organization/project/_git/repo",
+            Provider::AzureDevOps,
+        ),
+        (
+            "git@ssh.dev.azure.com:v3/organization/project/repo",
+            Provider::AzureDevOps,
+        ),
+        ("https://git.sr.ht/~owner/repo", Provider::SourceHut),
+        ("https://codeberg.org/owner/repo.git", Provider::Gitea),
+    ];
+
+    for (test_url, provider) in test_urls {
+        let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+        assert_eq!(parsed.provider(), provider, "{}", test_url);
+    }
+}
+
+#[test]
+fn provider_self_hosted() {
+    let parsed =
+        GitUrl::parse("https://gitlab.example.com/owner/repo.git").expect("URL parse failed");
+
+    assert_eq!(parsed.provider(), Provider::Unknown);
+}
+
+#[test]
+fn provider_with_overrides() {
+    let parsed =
+        GitUrl::parse("https://gitlab.example.com/owner/repo.git").expect("URL parse failed");
+    let overrides = [("gitlab.example.com", Provider::GitLab)];
+
+    assert_eq!(parsed.provider_with_overrides(&overrides), Provider::GitLab);
+}
+
+#[test]
+fn provider_file() {
+    let parsed = GitUrl::parse("/path/to/repo.git").expect("URL parse failed");
+
+    assert_eq!(parsed.provider(), Provider::Unknown);
+}
+
+#[test]
+fn normalize_case_github() {
+    let parsed = GitUrl::parse("git@github.com:Owner/Repo.git").expect("URL parse failed");
+    let normalized = parsed.normalize_case();
+
+    assert_eq!(normalized.owner, Some("owner".to_string()));
+    assert_eq!(normalized.name, "repo");
+    assert_eq!(normalized.fullname, "owner/repo");
+}
+
+#[test]
+fn normalize_case_gitlab_subgroups() {
+    let parsed =
+        GitUrl::parse("https://gitlab.com/Group/SubGroup/Repo.git").expect("URL parse failed");
+    let normalized = parsed.normalize_case();
+
+    assert_eq!(normalized.groups, vec!["group", "subgroup"]);
+    assert_eq!(normalized.fullname, "group/subgroup/repo");
+}
+
+#[test]
+fn normalize_case_case_sensitive_host() {
+    let parsed = GitUrl::parse("https://Git.Example.com/Owner/Repo.git").expect("URL parse failed");
+    let normalized = parsed.normalize_case();
+
+    assert_eq!(normalized.host, Some("git.example.com".to_string()));
+    assert_eq!(normalized.owner, Some("Owner".to_string()));
+    assert_eq!(normalized.name, "Repo");
+    assert_eq!(normalized.fullname, "Owner/Repo");
+}
+
+#[test]
+fn github_trailing_dot() {
+    let test_url = "https://github.com./owner/repo.git";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+    assert_eq!(parsed.provider(), Provider::GitHub);
+    assert_eq!(parsed.canonical(), "https://github.com/owner/repo");
+}
+
+#[test]
+fn github_trailing_dot_web_route() {
+    let test_url = "https://github.com./owner/repo/tree/main";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+    assert_eq!(parsed.fullname, "owner/repo");
+    assert_eq!(parsed.reference, Some("main".to_string()));
+}
+
+#[test]
+fn gist_scp() {
+    let test_url = "git@gist.github.com:abc123def.git";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+    assert!(parsed.is_gist());
+    assert_eq!(parsed.provider(), Provider::GitHub);
+    assert_eq!(parsed.owner, None);
+    assert_eq!(parsed.name, "abc123def");
+    assert_eq!(parsed.fullname, "abc123def");
+    assert_eq!(parsed.to_string(), test_url);
+}
+
+#[test]
+fn gist_https() {
+    let test_url = "https://gist.github.com/abc123def.git";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+    assert!(parsed.is_gist());
+    assert_eq!(parsed.owner, None);
+    assert_eq!(parsed.name, "abc123def");
+}
+
+#[test]
+fn gist_https_with_user() {
+    let test_url = "https://gist.github.com/user/abc123def";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+    assert!(parsed.is_gist());
+    assert_eq!(parsed.owner, Some("user".to_string()));
+    assert_eq!(parsed.name, "abc123def");
+}
+
+#[test]
+fn not_gist() {
+    let parsed = GitUrl::parse("git@github.com:owner/repo.git").expect("URL parse failed");
+
+    assert!(!parsed.is_gist());
+}
@@ -0,0 +1,175 @@
+use parse_git_url::*;
+
+#[test]
+fn clone_depth_hint() {
+    let test_url = "https://github.com/owner/repo.git?depth=1";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+    assert_eq!(parsed.clone_depth_hint(), Some(1));
+}
+
+#[test]
+fn clone_depth_hint_missing() {
+    let test_url = "https://github.com/owner/repo.git";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+    assert_eq!(parsed.clone_depth_hint(), None);
+}
+
+#[test]
+fn clone_depth_hint_unparseable() {
+    let test_url = "https://github.com/owner/repo.git?depth=shallow";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+    assert_eq!(parsed.clone_depth_hint(), None);
+}
+
+#[test]
+fn ssh_fragment() {
+    let test_url = "ssh://git@host.tld/owner/repo.git#subdir/file";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+    let expected = GitUrl {
+        host: Some("host.tld".to_string()),
+        name: "repo".to_string(),
+        owner: Some("owner".to_string()),
+        organization: None,
+        fullname: "owner/repo".to_string(),
+        scheme: Scheme::Ssh,
+        user: Some("git".to_string()),
+        token: None,
+        port: None,
+        path: "owner/repo.git".to_string(),
+        git_suffix: true,
+        scheme_prefix: true,
+        query: None,
+        fragment: Some("subdir/file".to_string()),
+        reference: None,
+        git_short_form: false,
+        git_plus_prefix: false,
+        git_prefix: false,
+        original_host: None,
+        groups: Vec::new(),
+        worktree: None,
+        comment: None,
+    };
+
+    assert_eq!(parsed, expected);
+}
+
+#[test]
+fn scp_fragment() {
+    let test_url = "git@host.tld:owner/repo.git#subdir/file";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+    assert_eq!(parsed.path, "owner/repo.git");
+    assert_eq!(parsed.name, "repo");
+    assert_eq!(parsed.fragment, Some("subdir/file".to_string()));
+    assert_eq!(format!("{}", parsed), test_url);
+}
+
+#[test]
+fn https_query_and_fragment() {
+    let test_url = "https://host.tld/owner/repo.git?path=sub#L5";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+    let expected = GitUrl {
+        host: Some("host.tld".to_string()),
+        name: "repo".to_string(),
+        owner: Some("owner".to_string()),
+        organization: None,
+        fullname: "owner/repo".to_string(),
+        scheme: Scheme::Https,
+        user: None,
+        token: None,
+        port: None,
+        path: "/owner/repo.git".to_string(),
+        git_suffix: true,
+        scheme_prefix: true,
+        query: Some("path=sub".to_string()),
+        fragment: Some("L5".to_string()),
+        reference: None,
+        git_short_form: false,
+        git_plus_prefix: false,
+        git_prefix: false,
+        original_host: None,
+        groups: Vec::new(),
+        worktree: None,
+        comment: None,
+    };
+
+    assert_eq!(parsed, expected);
+    assert_eq!(parsed.to_string(), test_url);
+}
+
+#[test]
+fn scp_query_and_fragment() {
+    let test_url = "git@host.tld:owner/repo.git?path=sub#L5";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+    assert_eq!(parsed.name, "repo");
+    assert_eq!(parsed.query, Some("path=sub".to_string()));
+    assert_eq!(parsed.fragment, Some("L5".to_string()));
+    assert_eq!(parsed.to_string(), test_url);
+}
+
+#[test]
+fn query_and_fragment_trailing_slash() {
+    let test_url = "https://host.tld/owner/repo.git/?path=sub#L5";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+    assert_eq!(parsed.name, "repo");
+    assert_eq!(parsed.query, Some("path=sub".to_string()));
+    assert_eq!(parsed.fragment, Some("L5".to_string()));
+    assert_eq!(
+        parsed.to_string(),
+        "https://host.tld/owner/repo.git?path=sub#L5"
+    );
+}
+
+#[test]
+fn strip_query_and_fragment() {
+    let test_url = "https://host.tld/owner/repo.git?path=sub#L5";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+    let stripped = parsed.strip_query_and_fragment();
+
+    assert_eq!(stripped.query, None);
+    assert_eq!(stripped.fragment, None);
+    assert_eq!(stripped.fullname, parsed.fullname);
+    assert_eq!(stripped.to_string(), "https://host.tld/owner/repo.git");
+}
+
+#[test]
+fn https_ref_query_round_trip() {
+    let test_url = "https://github.com/owner/repo.git?ref=main";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+    assert_eq!(parsed.name, "repo");
+    assert_eq!(parsed.fullname, "owner/repo");
+    assert_eq!(parsed.path, "/owner/repo.git");
+    assert!(parsed.git_suffix);
+    assert_eq!(parsed.query, Some("ref=main".to_string()));
+    assert_eq!(parsed.to_string(), test_url);
+    assert_eq!(GitUrl::parse(&parsed.to_string()).unwrap(), parsed);
+}
+
+#[test]
+fn https_query_without_suffix() {
+    let test_url = "https://github.com/owner/repo?ref=main&depth=1";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+    assert_eq!(parsed.name, "repo");
+    assert_eq!(parsed.owner, Some("owner".to_string()));
+    assert_eq!(parsed.query, Some("ref=main&depth=1".to_string()));
+    assert_eq!(parsed.to_string(), test_url);
+}
+
+#[test]
+fn scp_query_with_colons() {
+    let test_url = "git@github.com:owner/repo.git?mirror=https://example.com";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+    assert_eq!(parsed.name, "repo");
+    assert_eq!(parsed.fullname, "owner/repo");
+    assert!(!parsed.scheme_prefix);
+    assert_eq!(parsed.query, Some("mirror=https://example.com".to_string()));
+    assert_eq!(parsed.to_string(), test_url);
+}
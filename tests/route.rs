@@ -0,0 +1,376 @@
+use parse_git_url::*;
+
+#[test]
+fn github_archive() {
+    let test_url = "https://github.com/owner/repo/archive/refs/tags/v1.0.tar.gz";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+    let expected = GitUrl {
+        host: Some("github.com".to_string()),
+        name: "repo".to_string(),
+        owner: Some("owner".to_string()),
+        organization: None,
+        fullname: "owner/repo".to_string(),
+        scheme: Scheme::Https,
+        user: None,
+        token: None,
+        port: None,
+        path: "/owner/repo/archive/refs/tags/v1.0.tar.gz".to_string(),
+        git_suffix: false,
+        scheme_prefix: true,
+        query: None,
+        fragment: None,
+        reference: None,
+        git_short_form: false,
+        git_plus_prefix: false,
+        git_prefix: false,
+        original_host: None,
+        groups: Vec::new(),
+        worktree: None,
+        comment: None,
+    };
+
+    assert_eq!(parsed, expected);
+    assert!(parsed.is_archive());
+}
+
+#[test]
+fn github_archive_short() {
+    let test_url = "https://github.com/owner/repo/archive/v1.0.zip";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+    assert_eq!(parsed.owner, Some("owner".to_string()));
+    assert_eq!(parsed.name, "repo");
+    assert!(parsed.is_archive());
+}
+
+#[test]
+fn github_clone_not_archive() {
+    let test_url = "https://github.com/owner/repo.git";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+    assert!(!parsed.is_archive());
+}
+
+#[test]
+fn other_host_archive_not_recognized() {
+    let test_url = "https://example.com/owner/repo/archive/v1.0.tar.gz";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+    assert_eq!(parsed.name, "v1.0.tar.gz");
+    assert!(!parsed.is_archive());
+}
+
+#[test]
+fn github_tree() {
+    let test_url = "https://github.com/owner/repo/tree/main";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+    let expected = GitUrl {
+        host: Some("github.com".to_string()),
+        name: "repo".to_string(),
+        owner: Some("owner".to_string()),
+        organization: None,
+        fullname: "owner/repo".to_string(),
+        scheme: Scheme::Https,
+        user: None,
+        token: None,
+        port: None,
+        path: "/owner/repo/tree/main".to_string(),
+        git_suffix: false,
+        scheme_prefix: true,
+        query: None,
+        fragment: None,
+        reference: Some("main".to_string()),
+        git_short_form: false,
+        git_plus_prefix: false,
+        git_prefix: false,
+        original_host: None,
+        groups: Vec::new(),
+        worktree: None,
+        comment: None,
+    };
+
+    assert_eq!(parsed, expected);
+}
+
+#[test]
+fn github_tree_multi_segment_branch() {
+    let test_url = "https://github.com/owner/repo/tree/feature/x";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+    assert_eq!(parsed.owner, Some("owner".to_string()));
+    assert_eq!(parsed.name, "repo");
+    assert_eq!(parsed.reference, Some("feature/x".to_string()));
+}
+
+#[test]
+fn github_tree_encoded_branch() {
+    let test_url = "https://github.com/owner/repo/tree/feature%2Fx";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+    assert_eq!(parsed.name, "repo");
+    assert_eq!(parsed.reference, Some("feature/x".to_string()));
+}
+
+#[test]
+fn github_clone_no_reference() {
+    let test_url = "https://github.com/owner/repo.git";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+    assert_eq!(parsed.reference, None);
+}
+
+#[test]
+fn github_blob_head() {
+    let test_url = "https://github.com/owner/repo/blob/HEAD/src/lib.rs";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+    assert_eq!(parsed.owner, Some("owner".to_string()));
+    assert_eq!(parsed.name, "repo");
+    assert_eq!(parsed.fullname, "owner/repo");
+    assert_eq!(parsed.reference, Some("HEAD".to_string()));
+    assert!(!parsed.is_archive());
+}
+
+#[test]
+fn gitlab_raw_head() {
+    let test_url = "https://gitlab.com/owner/repo/raw/HEAD/README.md";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+    assert_eq!(parsed.owner, Some("owner".to_string()));
+    assert_eq!(parsed.name, "repo");
+    assert_eq!(parsed.reference, Some("HEAD".to_string()));
+}
+
+#[test]
+fn gitlab_raw_head_subgroup() {
+    let test_url = "https://gitlab.com/group/subgroup/repo/raw/HEAD/README.md";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+    assert_eq!(parsed.name, "repo");
+    assert_eq!(parsed.fullname, "group/subgroup/repo");
+    assert_eq!(parsed.groups, vec!["group", "subgroup"]);
+    assert_eq!(parsed.reference, Some("HEAD".to_string()));
+}
+
+#[test]
+fn gitlab_dash_blob_head() {
+    let test_url = "https://gitlab.com/owner/repo/-/blob/HEAD/src/lib.rs";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+    assert_eq!(parsed.owner, Some("owner".to_string()));
+    assert_eq!(parsed.name, "repo");
+    assert_eq!(parsed.fullname, "owner/repo");
+    assert_eq!(parsed.reference, Some("HEAD".to_string()));
+}
+
+#[test]
+fn gitlab_dash_raw_head_subgroup() {
+    let test_url = "https://gitlab.com/group/subgroup/repo/-/raw/HEAD/README.md";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+    assert_eq!(parsed.name, "repo");
+    assert_eq!(parsed.fullname, "group/subgroup/repo");
+    assert_eq!(parsed.reference, Some("HEAD".to_string()));
+    assert_eq!(
+        parsed.to_https().to_string(),
+        "https://gitlab.com/group/subgroup/repo"
+    );
+}
+
+#[test]
+fn gitlab_dash_without_repo_not_recognized() {
+    let test_url = "https://gitlab.com/-/blob/HEAD/README.md";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+    assert_eq!(parsed.reference, None);
+}
+
+#[test]
+fn github_blob_branch_not_recognized() {
+    let test_url = "https://github.com/owner/repo/blob/main/README.md";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+    assert_eq!(parsed.name, "README.md");
+    assert_eq!(parsed.reference, None);
+}
+
+#[test]
+fn smart_http_info_refs() {
+    let test_url = "https://github.com/owner/repo.git/info/refs?service=git-upload-pack";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+    assert_eq!(parsed.owner, Some("owner".to_string()));
+    assert_eq!(parsed.name, "repo");
+    assert_eq!(parsed.path, "/owner/repo.git");
+    assert!(parsed.git_suffix);
+    assert_eq!(parsed.query, Some("service=git-upload-pack".to_string()));
+}
+
+#[test]
+fn smart_http_upload_pack() {
+    let test_url = "https://host.tld/owner/repo.git/git-upload-pack";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+    assert_eq!(parsed.owner, Some("owner".to_string()));
+    assert_eq!(parsed.name, "repo");
+    assert_eq!(parsed.fullname, "owner/repo");
+}
+
+#[test]
+fn smart_http_receive_pack() {
+    let test_url = "http://host.tld/owner/repo/git-receive-pack";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+    assert_eq!(parsed.owner, Some("owner".to_string()));
+    assert_eq!(parsed.name, "repo");
+    assert!(!parsed.git_suffix);
+}
+
+#[test]
+fn smart_http_upload_archive() {
+    let test_url = "https://host.tld/owner/repo.git/git-upload-archive";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+    assert_eq!(parsed.name, "repo");
+}
+
+#[test]
+fn github_issues_tab() {
+    let test_url = "https://github.com/owner/repo/issues";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+    assert_eq!(parsed.owner, Some("owner".to_string()));
+    assert_eq!(parsed.name, "repo");
+    assert_eq!(parsed.web_tab(), Some("issues"));
+    assert_eq!(parsed.reference, None);
+}
+
+#[test]
+fn github_actions_tab_nested() {
+    let test_url = "https://github.com/owner/repo/actions/runs/123";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+    assert_eq!(parsed.owner, Some("owner".to_string()));
+    assert_eq!(parsed.name, "repo");
+    assert_eq!(parsed.web_tab(), Some("actions"));
+}
+
+#[test]
+fn github_clone_no_tab() {
+    let test_url = "https://github.com/owner/repo.git";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+    assert_eq!(parsed.web_tab(), None);
+}
+
+#[test]
+fn github_tree_not_tab() {
+    let test_url = "https://github.com/owner/repo/tree/main";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+    assert_eq!(parsed.web_tab(), None);
+}
+
+#[test]
+fn github_release_tag() {
+    let test_url = "https://github.com/owner/repo/releases/tag/v1.0";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+    assert_eq!(parsed.owner, Some("owner".to_string()));
+    assert_eq!(parsed.name, "repo");
+    assert_eq!(parsed.fullname, "owner/repo");
+    assert_eq!(parsed.reference, Some("v1.0".to_string()));
+    assert!(!parsed.is_archive());
+}
+
+#[test]
+fn github_release_tag_with_slash() {
+    let test_url = "https://github.com/owner/repo/releases/tag/release/2024.1";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+    assert_eq!(parsed.reference, Some("release/2024.1".to_string()));
+}
+
+#[test]
+fn github_releases_page() {
+    let test_url = "https://github.com/owner/repo/releases";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+    assert_eq!(parsed.name, "repo");
+    assert_eq!(parsed.reference, None);
+}
+
+#[test]
+fn at_ref() {
+    let test_url = "https://github.com/owner/repo.git@v1";
+    let parsed = GitUrl::parse_allowing_fragment_refs(test_url).expect("URL parse failed");
+
+    assert_eq!(parsed.reference, Some("v1".to_string()));
+    assert_eq!(parsed.fullname, "owner/repo");
+    assert_eq!(parsed.fragment, None);
+    assert_eq!(parsed.to_string(), "https://github.com/owner/repo.git");
+}
+
+#[test]
+fn at_ref_scp() {
+    let test_url = "git@github.com:owner/repo.git@v1";
+    let parsed = GitUrl::parse_allowing_fragment_refs(test_url).expect("URL parse failed");
+
+    assert_eq!(parsed.user, Some("git".to_string()));
+    assert_eq!(parsed.reference, Some("v1".to_string()));
+    assert_eq!(parsed.to_string(), "git@github.com:owner/repo.git");
+}
+
+#[test]
+fn fragment_ref() {
+    let test_url = "https://github.com/owner/repo.git#v1";
+    let parsed = GitUrl::parse_allowing_fragment_refs(test_url).expect("URL parse failed");
+
+    assert_eq!(parsed.reference, Some("v1".to_string()));
+    assert_eq!(parsed.fragment, None);
+    assert_eq!(parsed.to_string(), "https://github.com/owner/repo.git");
+}
+
+#[test]
+fn at_ref_before_fragment() {
+    let test_url = "git+https://github.com/owner/repo.git@v1#egg=repo";
+    let parsed = GitUrl::parse_allowing_fragment_refs(test_url).expect("URL parse failed");
+
+    assert_eq!(parsed.scheme, Scheme::Https);
+    assert_eq!(parsed.host, Some("github.com".to_string()));
+    assert_eq!(parsed.fullname, "owner/repo");
+    assert_eq!(parsed.reference, Some("v1".to_string()));
+    assert_eq!(parsed.fragment, Some("egg=repo".to_string()));
+    assert_eq!(
+        parsed.to_string(),
+        "https://github.com/owner/repo.git#egg=repo"
+    );
+}
+
+#[test]
+fn pip_egg_fragment_without_ref() {
+    let test_url = "git+https://github.com/owner/repo.git#egg=repo";
+    let parsed = GitUrl::parse_allowing_fragment_refs(test_url).expect("URL parse failed");
+
+    assert_eq!(parsed.host, Some("github.com".to_string()));
+    assert_eq!(parsed.reference, None);
+    assert_eq!(parsed.fragment, Some("egg=repo".to_string()));
+}
+
+#[test]
+fn line_anchor_fragment_not_ref() {
+    let test_url = "https://github.com/owner/repo/tree/main#L5";
+    let parsed = GitUrl::parse_allowing_fragment_refs(test_url).expect("URL parse failed");
+
+    assert_eq!(parsed.reference, Some("main".to_string()));
+    assert_eq!(parsed.fragment, Some("L5".to_string()));
+}
+
+#[test]
+fn no_ref() {
+    let test_url = "https://user@github.com/owner/repo.git";
+    let parsed = GitUrl::parse_allowing_fragment_refs(test_url).expect("URL parse failed");
+
+    assert_eq!(parsed, GitUrl::parse(test_url).expect("URL parse failed"));
+    assert_eq!(parsed.reference, None);
+}
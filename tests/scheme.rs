@@ -0,0 +1,88 @@
+use parse_git_url::*;
+
+#[test]
+fn from_port_ftp() {
+    assert_eq!(Scheme::from_port(21), Some(Scheme::Ftp));
+}
+
+#[test]
+fn from_port_ssh() {
+    assert_eq!(Scheme::from_port(22), Some(Scheme::Ssh));
+}
+
+#[test]
+fn from_port_http() {
+    assert_eq!(Scheme::from_port(80), Some(Scheme::Http));
+}
+
+#[test]
+fn from_port_https() {
+    assert_eq!(Scheme::from_port(443), Some(Scheme::Https));
+}
+
+#[test]
+fn from_port_ftps() {
+    assert_eq!(Scheme::from_port(990), Some(Scheme::Ftps));
+}
+
+#[test]
+fn from_port_git() {
+    assert_eq!(Scheme::from_port(9418), Some(Scheme::Git));
+}
+
+#[test]
+fn from_port_rsync() {
+    assert_eq!(Scheme::from_port(873), Some(Scheme::Rsync));
+}
+
+#[test]
+fn from_port_unknown() {
+    assert_eq!(Scheme::from_port(8080), None);
+}
+
+#[test]
+fn default_port_round_trip() {
+    for scheme in [
+        Scheme::Ftp,
+        Scheme::Ftps,
+        Scheme::Git,
+        Scheme::Http,
+        Scheme::Https,
+        Scheme::Rsync,
+        Scheme::Ssh,
+    ] {
+        let port = scheme.default_port().expect("scheme has a default port");
+
+        assert_eq!(Scheme::from_port(port), Some(scheme));
+    }
+}
+
+#[test]
+fn default_port_none() {
+    assert_eq!(Scheme::File.default_port(), None);
+    assert_eq!(Scheme::Unspecified.default_port(), None);
+}
+
+#[test]
+fn requires_prefix() {
+    assert!(Scheme::Https.requires_prefix());
+    assert!(Scheme::Git.requires_prefix());
+    assert!(!Scheme::Ssh.requires_prefix());
+    assert!(!Scheme::File.requires_prefix());
+}
+
+#[test]
+fn from_str_case_insensitive() {
+    assert_eq!("HTTPS".parse::<Scheme>().unwrap(), Scheme::Https);
+    assert_eq!("Git".parse::<Scheme>().unwrap(), Scheme::Git);
+    assert_eq!("Git+SSH".parse::<Scheme>().unwrap(), Scheme::GitSsh);
+}
+
+#[test]
+fn from_str_unsupported() {
+    let e = "Gopher"
+        .parse::<Scheme>()
+        .expect_err("Scheme parse should fail");
+
+    assert_eq!(e.to_string(), "unsupported scheme `Gopher`");
+}
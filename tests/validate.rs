@@ -0,0 +1,82 @@
+use parse_git_url::*;
+
+#[test]
+fn parsed_urls_are_valid() {
+    let test_urls = [
+        "https://github.com/owner/repo.git",
+        "git@github.com:owner/repo.git",
+        "ssh://git@host.tld:2222/owner/repo.git",
+        "git:host.tld/owner/repo.git",
+        "https://dev.azure.com/organization/project/_git/repo",
+        "git@ssh.dev.azure.com:v3/organization/project/repo",
+        "https://github.com/owner/repo/tree/main",
+        "/path/to/repo.git",
+    ];
+
+    for test_url in test_urls {
+        let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+        assert!(parsed.validate().is_ok(), "{} is invalid", test_url);
+    }
+}
+
+#[test]
+fn missing_host() {
+    let parsed = GitUrl::parse("https://github.com/owner/repo.git").expect("URL parse failed");
+    let git_url = GitUrl {
+        host: None,
+        ..parsed
+    };
+    let e = git_url.validate().expect_err("Validation should fail");
+
+    assert!(matches!(e.kind(), ValidationErrorKind::MissingHost { .. }));
+}
+
+#[test]
+fn fullname_mismatch() {
+    let parsed = GitUrl::parse("https://github.com/owner/repo.git").expect("URL parse failed");
+    let git_url = GitUrl {
+        fullname: "other/repo".to_string(),
+        ..parsed
+    };
+    let e = git_url.validate().expect_err("Validation should fail");
+
+    assert!(matches!(
+        e.kind(),
+        ValidationErrorKind::FullnameMismatch { expected, .. } if expected == "owner/repo"
+    ));
+}
+
+#[test]
+fn missing_scheme_prefix() {
+    let parsed = GitUrl::parse("https://github.com/owner/repo.git").expect("URL parse failed");
+    let git_url = GitUrl {
+        scheme_prefix: false,
+        ..parsed
+    };
+    let e = git_url.validate().expect_err("Validation should fail");
+
+    assert!(matches!(
+        e.kind(),
+        ValidationErrorKind::MissingSchemePrefix { .. }
+    ));
+}
+
+#[test]
+fn invalid_port() {
+    let parsed = GitUrl::parse("https://github.com/owner/repo.git").expect("URL parse failed");
+    let git_url = GitUrl {
+        port: Some(0),
+        ..parsed
+    };
+    let e = git_url.validate().expect_err("Validation should fail");
+
+    assert!(matches!(e.kind(), ValidationErrorKind::InvalidPort { .. }));
+}
+
+#[test]
+fn gitlab_subgroups_are_valid() {
+    let parsed =
+        GitUrl::parse("https://gitlab.com/group/subgroup/repo.git").expect("URL parse failed");
+
+    assert!(parsed.validate().is_ok());
+}